@@ -0,0 +1,439 @@
+//! Achievements plugin - evaluates the data-driven achievement table against
+//! game messages, persists unlocks, and shows unlock toasts during play.
+//!
+//! Every achievement is one row of [`ACHIEVEMENTS`]: an id, display text, and
+//! a [`Goal`]. Progress for the current run lives in [`RunProgress`] and is
+//! fed only by messages (`FoodEatenEvent`, `GrowthEvent`, `TurnEvent`) and
+//! `GamePhase` transitions, so no gameplay system knows achievements exist.
+
+use bevy::prelude::*;
+use bevy::text::FontWeight;
+
+use std::collections::VecDeque;
+
+use crate::game::{
+    AchievementToast, AchievementUnlocked, Arena, FoodEatenEvent, GameMode, GamePhase, GameSet,
+    GameState, GrowthEvent, SaveRequested, TurnEvent, UnlockedAchievements,
+};
+use crate::locale::Locale;
+use crate::theme::Theme;
+
+/// How long an unlock toast stays on screen, including its slide/fade.
+const TOAST_SECONDS: f32 = 3.5;
+/// Share of [`TOAST_SECONDS`] spent sliding in at the start and fading out
+/// at the end.
+const TOAST_EASE_FRACTION: f32 = 0.12;
+/// How far off the right edge a toast starts its slide-in, in pixels.
+const TOAST_SLIDE_PX: f32 = 320.0;
+/// Vertical spacing between stacked toasts, in pixels.
+const TOAST_STACK_PX: f32 = 64.0;
+
+/// What a run has to do to unlock an achievement.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Goal {
+    /// Eat this many apples in a single run.
+    ApplesInRun(usize),
+    /// Reach this total snake length (head included) in a single run.
+    LengthReached(usize),
+    /// Win on an arena no larger than `width` × `height`.
+    WinOnArena { width: u32, height: u32 },
+    /// Stay alive for this many seconds in a single run.
+    SurviveSecs(f32),
+    /// Make `turns` turns within any `secs`-second window.
+    TurnsWithin { turns: usize, secs: f32 },
+}
+
+/// One row of the achievement table.
+#[derive(Debug, Clone, Copy)]
+pub struct Achievement {
+    /// Stable key written to the settings file — never rename a shipped id.
+    pub id: &'static str,
+    /// English display text; translations live in the message catalogues
+    /// under `achievement.<id>.title` / `.description`.
+    pub title: &'static str,
+    pub description: &'static str,
+    pub goal: Goal,
+}
+
+impl Achievement {
+    /// The title in `locale`'s language, or English if it has none.
+    pub fn title(&self, locale: &Locale) -> String {
+        let key = format!("achievement.{}.title", self.id);
+        locale.get(&key).unwrap_or(self.title).to_string()
+    }
+
+    /// The description in `locale`'s language, or English if it has none.
+    pub fn description(&self, locale: &Locale) -> String {
+        let key = format!("achievement.{}.description", self.id);
+        locale.get(&key).unwrap_or(self.description).to_string()
+    }
+}
+
+/// The achievement table. Order is display order in the gallery.
+pub const ACHIEVEMENTS: &[Achievement] = &[
+    Achievement {
+        id: "first_bite",
+        title: "First Bite",
+        description: "Eat your first apple",
+        goal: Goal::ApplesInRun(1),
+    },
+    Achievement {
+        id: "hungry",
+        title: "Hungry",
+        description: "Eat 10 apples in one run",
+        goal: Goal::ApplesInRun(10),
+    },
+    Achievement {
+        id: "glutton",
+        title: "Glutton",
+        description: "Eat 50 apples in one run",
+        goal: Goal::ApplesInRun(50),
+    },
+    Achievement {
+        id: "long_boi",
+        title: "Long Boi",
+        description: "Grow to a length of 100",
+        goal: Goal::LengthReached(100),
+    },
+    Achievement {
+        id: "survivor",
+        title: "Survivor",
+        description: "Survive for 5 minutes",
+        goal: Goal::SurviveSecs(300.0),
+    },
+    Achievement {
+        id: "nimble",
+        title: "Nimble",
+        description: "Make 10 turns in 2 seconds",
+        goal: Goal::TurnsWithin {
+            turns: 10,
+            secs: 2.0,
+        },
+    },
+    Achievement {
+        id: "small_world",
+        title: "Small World",
+        description: "Win on a 10x10 arena",
+        goal: Goal::WinOnArena {
+            width: 10,
+            height: 10,
+        },
+    },
+    Achievement {
+        id: "conqueror",
+        title: "Conqueror",
+        description: "Fill the whole arena",
+        // Any arena size counts.
+        goal: Goal::WinOnArena {
+            width: u32::MAX,
+            height: u32::MAX,
+        },
+    },
+];
+
+/// Per-run counters the goals are evaluated against. Reset whenever a new
+/// run starts.
+#[derive(Resource, Default)]
+pub struct RunProgress {
+    pub apples: usize,
+    pub length: usize,
+    pub elapsed_secs: f32,
+    /// `elapsed_secs` timestamps of recent turns, oldest first. Trimmed to
+    /// the longest `TurnsWithin` window so it never grows unbounded.
+    pub recent_turns: VecDeque<f32>,
+    pub won: bool,
+    /// The arena this run is played on.
+    pub arena: Arena,
+}
+
+impl RunProgress {
+    /// Whether this run has met `goal`.
+    pub fn satisfies(&self, goal: Goal) -> bool {
+        match goal {
+            Goal::ApplesInRun(apples) => self.apples >= apples,
+            Goal::LengthReached(length) => self.length >= length,
+            Goal::WinOnArena { width, height } => {
+                self.won && self.arena.width <= width && self.arena.height <= height
+            }
+            Goal::SurviveSecs(secs) => self.elapsed_secs >= secs,
+            Goal::TurnsWithin { turns, secs } => {
+                let window_start = self.elapsed_secs - secs;
+                self.recent_turns
+                    .iter()
+                    .filter(|&&t| t >= window_start)
+                    .count()
+                    >= turns
+            }
+        }
+    }
+}
+
+/// Longest `TurnsWithin` window in the table — how much turn history
+/// [`RunProgress`] needs to keep.
+fn longest_turn_window() -> f32 {
+    ACHIEVEMENTS
+        .iter()
+        .filter_map(|a| match a.goal {
+            Goal::TurnsWithin { secs, .. } => Some(secs),
+            _ => None,
+        })
+        .fold(0.0, f32::max)
+}
+
+/// Plugin for achievement tracking and unlock toasts.
+pub struct AchievementsPlugin;
+
+impl Plugin for AchievementsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RunProgress>().add_systems(
+            Update,
+            (
+                track_run_progress,
+                evaluate_achievements,
+                spawn_achievement_toasts,
+                animate_achievement_toasts,
+            )
+                .chain()
+                .in_set(GameSet::Ui),
+        );
+    }
+}
+
+/// Folds this frame's game messages and phase transitions into
+/// [`RunProgress`].
+///
+/// A transition into `Playing` (from the menu or an end screen) starts a
+/// fresh run. Messages are drained every frame regardless of phase so none
+/// carry over into the next run.
+fn track_run_progress(
+    time: Res<Time>,
+    game_state: Res<GameState>,
+    arena: Res<Arena>,
+    mut progress: ResMut<RunProgress>,
+    mut last_phase: Local<Option<GamePhase>>,
+    mut food_eaten: MessageReader<FoodEatenEvent>,
+    mut growth: MessageReader<GrowthEvent>,
+    mut turns: MessageReader<TurnEvent>,
+) {
+    // The final bite of a winning run lands on the same frame as the switch
+    // to `Won`, so messages still count on the frame a run ends.
+    let was_playing = *last_phase == Some(GamePhase::Playing);
+    if *last_phase != Some(game_state.phase) {
+        if game_state.phase == GamePhase::Playing {
+            // Every run starts as a lone head.
+            *progress = RunProgress {
+                length: 1,
+                arena: *arena,
+                ..default()
+            };
+        }
+        if game_state.phase == GamePhase::Won {
+            progress.won = true;
+        }
+        *last_phase = Some(game_state.phase);
+    }
+
+    let apples = food_eaten.read().count();
+    let grown = growth.read().count();
+    let turned = turns.read().count();
+    let playing = game_state.phase == GamePhase::Playing;
+    if !(playing || was_playing) {
+        return;
+    }
+
+    if playing {
+        progress.elapsed_secs += time.delta_secs();
+    }
+    progress.apples += apples;
+    progress.length += grown;
+
+    let now = progress.elapsed_secs;
+    for _ in 0..turned {
+        progress.recent_turns.push_back(now);
+    }
+    let keep_after = now - longest_turn_window();
+    while progress
+        .recent_turns
+        .front()
+        .is_some_and(|&t| t < keep_after)
+    {
+        progress.recent_turns.pop_front();
+    }
+}
+
+/// Unlocks every not-yet-unlocked achievement whose goal the current run has
+/// met, persists the new set, and announces each unlock.
+///
+/// Zen runs can't die, which would trivialize most goals, so they never
+/// unlock anything.
+fn evaluate_achievements(
+    mut saves: MessageWriter<SaveRequested>,
+    progress: Res<RunProgress>,
+    game_mode: Res<GameMode>,
+    mut unlocked: ResMut<UnlockedAchievements>,
+    mut unlock_writer: MessageWriter<AchievementUnlocked>,
+) {
+    if !progress.is_changed() || *game_mode == GameMode::Zen {
+        return;
+    }
+
+    let mut any_new = false;
+    for (index, achievement) in ACHIEVEMENTS.iter().enumerate() {
+        if unlocked.contains(achievement.id) || !progress.satisfies(achievement.goal) {
+            continue;
+        }
+        unlocked.ids.push(achievement.id.to_string());
+        unlock_writer.write(AchievementUnlocked { index });
+        info!("Achievement unlocked: {}", achievement.title);
+        any_new = true;
+    }
+    if any_new {
+        saves.write(SaveRequested);
+    }
+}
+
+/// Spawns one toast per unlock, stacked below any toasts still on screen.
+fn spawn_achievement_toasts(
+    mut commands: Commands,
+    mut unlocks: MessageReader<AchievementUnlocked>,
+    theme: Res<Theme>,
+    locale: Res<Locale>,
+    toasts: Query<(), With<AchievementToast>>,
+) {
+    let mut slot = toasts.iter().count();
+    for unlock in unlocks.read() {
+        let Some(achievement) = ACHIEVEMENTS.get(unlock.index) else {
+            continue;
+        };
+        commands.spawn_scene(achievement_toast(achievement, slot, &theme, &locale));
+        slot += 1;
+    }
+}
+
+/// A single unlock toast: a card bordered in the theme's record color,
+/// anchored to the top-right, spawned off-screen and slid in by
+/// `animate_achievement_toasts`.
+fn achievement_toast(
+    achievement: &Achievement,
+    slot: usize,
+    theme: &Theme,
+    locale: &Locale,
+) -> impl Scene + use<> {
+    let title = locale.format(
+        "achievements.toast",
+        &[("title", &achievement.title(locale))],
+    );
+    let description = achievement.description(locale);
+    let top = 10.0 + slot as f32 * TOAST_STACK_PX;
+    let (background, border, detail) = (theme.toast_bg, theme.record, theme.hint);
+    bsn! {
+        AchievementToast {
+            timer: { Timer::from_seconds(TOAST_SECONDS, TimerMode::Once) },
+        }
+        Node {
+            position_type: PositionType::Absolute,
+            top: { Val::Px(top) },
+            right: { Val::Px(-TOAST_SLIDE_PX) },
+            padding: { UiRect::axes(Val::Px(14.0), Val::Px(8.0)) },
+            border: { UiRect::all(Val::Px(2.0)) },
+            border_radius: { BorderRadius::all(Val::Px(8.0)) },
+            flex_direction: FlexDirection::Column,
+        }
+        BackgroundColor(background)
+        BorderColor::from(border)
+        Children [
+            (
+                Text(title)
+                TextFont {
+                    font_size: { FontSize::Px(16.0) },
+                    weight: FontWeight::BOLD,
+                }
+                TextColor(border)
+            ),
+            (
+                Text(description)
+                TextFont {
+                    font_size: { FontSize::Px(13.0) },
+                }
+                TextColor(detail)
+            ),
+        ]
+    }
+}
+
+/// Slides toasts in from the right edge, holds them, then fades them out and
+/// despawns them.
+fn animate_achievement_toasts(
+    mut commands: Commands,
+    time: Res<Time>,
+    theme: Res<Theme>,
+    mut toasts: Query<(
+        Entity,
+        &mut AchievementToast,
+        &mut Node,
+        &mut BackgroundColor,
+        &mut BorderColor,
+    )>,
+) {
+    for (entity, mut toast, mut node, mut bg, mut border) in &mut toasts {
+        toast.timer.tick(time.delta());
+        if toast.timer.is_finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        let t = toast.timer.fraction();
+        // Ease-out cubic on the way in; linear fade on the way out.
+        let slide_in = (t / TOAST_EASE_FRACTION).min(1.0);
+        let eased = 1.0 - (1.0 - slide_in).powi(3);
+        node.right = Val::Px(10.0 - (1.0 - eased) * (TOAST_SLIDE_PX + 10.0));
+
+        let fade = ((1.0 - t) / TOAST_EASE_FRACTION).min(1.0);
+        bg.0 = theme.toast_bg.with_alpha(theme.toast_bg.alpha() * fade);
+        *border = BorderColor::from(theme.record.with_alpha(fade));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The goal of the achievement with `id`.
+    fn goal(id: &str) -> Goal {
+        ACHIEVEMENTS
+            .iter()
+            .find(|achievement| achievement.id == id)
+            .expect("an achievement with that id")
+            .goal
+    }
+
+    /// A won run on a `side` × `side` arena.
+    fn won_on(side: u32) -> RunProgress {
+        RunProgress {
+            won: true,
+            arena: Arena {
+                width: side,
+                height: side,
+                ..default()
+            },
+            ..default()
+        }
+    }
+
+    #[test]
+    fn small_world_needs_a_win_on_a_small_arena() {
+        let small_world = goal("small_world");
+        assert!(won_on(10).satisfies(small_world));
+        assert!(!won_on(20).satisfies(small_world));
+        let lost = RunProgress {
+            won: false,
+            ..won_on(10)
+        };
+        assert!(!lost.satisfies(small_world));
+    }
+
+    #[test]
+    fn conqueror_takes_a_win_on_any_arena() {
+        assert!(won_on(25).satisfies(goal("conqueror")));
+    }
+}
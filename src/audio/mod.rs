@@ -0,0 +1,299 @@
+//! Audio plugin - plays sound effects and generative background music
+//! synthesized in code at startup, so the game ships without any audio asset
+//! files.
+//!
+//! [`synth`] and [`music`] produce plain sample buffers; this module wraps
+//! them in WAV `AudioSource` assets once, then spawns one-shot players in
+//! response to game messages, `GamePhase` transitions and the music
+//! sequencer.
+
+pub mod music;
+pub mod synth;
+
+use std::time::Duration;
+
+use bevy::audio::Volume;
+use bevy::prelude::*;
+
+use crate::game::{
+    Arena, AudioSettings, FoodEatenEvent, GamePhase, GameSet, GameState, MOVE_INTERVAL,
+    SaveRequested, TurnEvent,
+};
+use music::{Layer, Voice};
+
+/// Apples eaten within this many seconds of each other build a combo.
+const COMBO_WINDOW_SECS: f32 = 2.0;
+/// Highest combo level with its own pitch; longer streaks stay on top.
+const MAX_COMBO: usize = 14;
+/// Turn ticks fire constantly, so they sit well under the other effects.
+const TURN_VOLUME: f32 = 0.35;
+/// Music gain on the game-over and time-up screens.
+const DUCKED_MUSIC: f32 = 0.3;
+
+/// Handles to every synthesized sound effect.
+#[derive(Resource)]
+struct SoundBank {
+    /// One blip per combo level, rising in pitch.
+    eat: Vec<Handle<AudioSource>>,
+    turn: Handle<AudioSource>,
+    death: Handle<AudioSource>,
+    win: Handle<AudioSource>,
+    time_up: Handle<AudioSource>,
+}
+
+/// Handles to every synthesized music note.
+#[derive(Resource)]
+struct MusicBank {
+    /// Bass note per chord of [`music::PROGRESSION`].
+    bass: Vec<Handle<AudioSource>>,
+    /// Arpeggio notes per chord, per chord tone.
+    arp: Vec<Vec<Handle<AudioSource>>>,
+    hat: Handle<AudioSource>,
+    cadence: Handle<AudioSource>,
+}
+
+impl MusicBank {
+    fn voice(&self, voice: Voice) -> &Handle<AudioSource> {
+        match voice.layer {
+            Layer::Bass => &self.bass[voice.chord],
+            Layer::Arp => &self.arp[voice.chord][voice.tone],
+            Layer::Hats => &self.hat,
+        }
+    }
+}
+
+/// Music sequencer position: the next step to play and the time banked
+/// towards it.
+#[derive(Default)]
+struct Sequencer {
+    step: usize,
+    elapsed: Duration,
+}
+
+/// The current eating streak: how many quick apples in a row, and how long
+/// since the last one.
+struct Combo {
+    level: usize,
+    since_last_secs: f32,
+}
+
+impl Default for Combo {
+    /// No apple yet, so the first one never counts as a combo.
+    fn default() -> Self {
+        Combo {
+            level: 0,
+            since_last_secs: f32::INFINITY,
+        }
+    }
+}
+
+/// Plugin for synthesized sound effects and music.
+pub struct SoundPlugin;
+
+impl Plugin for SoundPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, synthesize_sounds).add_systems(
+            Update,
+            (
+                toggle_mute,
+                play_food_sounds,
+                play_turn_sounds,
+                play_phase_sounds,
+                play_music,
+            )
+                .chain()
+                .in_set(GameSet::Ui),
+        );
+    }
+}
+
+/// Renders every effect into a WAV `AudioSource` asset.
+fn synthesize_sounds(mut commands: Commands, mut sources: ResMut<Assets<AudioSource>>) {
+    let mut add = |samples: Vec<f32>| {
+        sources.add(AudioSource {
+            bytes: synth::encode_wav(&samples).into(),
+        })
+    };
+    let bank = SoundBank {
+        eat: (0..=MAX_COMBO)
+            .map(|level| add(synth::eat(level)))
+            .collect(),
+        turn: add(synth::turn()),
+        death: add(synth::death()),
+        win: add(synth::win()),
+        time_up: add(synth::time_up()),
+    };
+    commands.insert_resource(bank);
+
+    // One sequencer step per snake move, so the music keeps the snake's
+    // tempo. Notes are cut for the default speed; at other speeds they
+    // overlap or leave small gaps, which the envelopes hide.
+    let step_secs = MOVE_INTERVAL.as_secs_f32();
+    let chords = 0..music::PROGRESSION.len();
+    let music_bank = MusicBank {
+        bass: chords
+            .clone()
+            .map(|chord| add(music::bass_note(chord, step_secs)))
+            .collect(),
+        arp: chords
+            .map(|chord| {
+                (0..4)
+                    .map(|tone| add(music::arp_note(chord, tone, step_secs)))
+                    .collect()
+            })
+            .collect(),
+        hat: add(music::hat()),
+        cadence: add(music::cadence()),
+    };
+    commands.insert_resource(music_bank);
+}
+
+/// Spawns a self-despawning one-shot player for `sound`, unless muted.
+fn play(commands: &mut Commands, sound: &Handle<AudioSource>, volume: f32) {
+    if volume <= 0.0 {
+        return;
+    }
+    commands.spawn((
+        AudioPlayer(sound.clone()),
+        PlaybackSettings::DESPAWN.with_volume(Volume::Linear(volume)),
+    ));
+}
+
+/// M toggles mute at any time; the choice is saved immediately.
+fn toggle_mute(
+    mut saves: MessageWriter<SaveRequested>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut settings: ResMut<AudioSettings>,
+) {
+    if keyboard_input.just_pressed(KeyCode::KeyM) {
+        settings.muted = !settings.muted;
+        saves.write(SaveRequested);
+    }
+}
+
+/// Plays the eat blip, one scale step higher for each apple in a combo.
+///
+/// The streak starts over with every run, so a previous run's last apple
+/// never makes the next run's first one a combo.
+#[allow(clippy::too_many_arguments)]
+fn play_food_sounds(
+    mut commands: Commands,
+    time: Res<Time>,
+    game_state: Res<GameState>,
+    bank: Res<SoundBank>,
+    settings: Res<AudioSettings>,
+    mut food_eaten: MessageReader<FoodEatenEvent>,
+    mut combo: Local<Combo>,
+    mut was_playing: Local<bool>,
+) {
+    let playing = game_state.phase == GamePhase::Playing;
+    if playing && !*was_playing {
+        *combo = Combo::default();
+    }
+    *was_playing = playing;
+    combo.since_last_secs += time.delta_secs();
+    for _ in food_eaten.read() {
+        combo.level = if combo.since_last_secs <= COMBO_WINDOW_SECS {
+            (combo.level + 1).min(MAX_COMBO)
+        } else {
+            0
+        };
+        combo.since_last_secs = 0.0;
+        play(
+            &mut commands,
+            &bank.eat[combo.level],
+            settings.effective_volume(),
+        );
+    }
+}
+
+/// Plays a soft tick whenever the snake changes heading.
+fn play_turn_sounds(
+    mut commands: Commands,
+    bank: Res<SoundBank>,
+    settings: Res<AudioSettings>,
+    mut turns: MessageReader<TurnEvent>,
+) {
+    // Several turns in one frame would only stack into one louder click.
+    if turns.read().count() > 0 {
+        play(
+            &mut commands,
+            &bank.turn,
+            settings.effective_volume() * TURN_VOLUME,
+        );
+    }
+}
+
+/// Plays the thud, fanfare or buzzer when a run ends.
+fn play_phase_sounds(
+    mut commands: Commands,
+    game_state: Res<GameState>,
+    bank: Res<SoundBank>,
+    settings: Res<AudioSettings>,
+    mut last_phase: Local<Option<GamePhase>>,
+) {
+    if *last_phase == Some(game_state.phase) {
+        return;
+    }
+    *last_phase = Some(game_state.phase);
+
+    let sound = match game_state.phase {
+        GamePhase::GameOver => &bank.death,
+        GamePhase::Won => &bank.win,
+        GamePhase::TimeUp => &bank.time_up,
+        _ => return,
+    };
+    play(&mut commands, sound, settings.effective_volume());
+}
+
+/// Drives the background music from `GameState`.
+///
+/// While playing, the sequencer advances one step per snake move and
+/// layers join as the score grows (see [`music::step_voices`]). The
+/// game-over and time-up screens keep a ducked bass line going; a win stops
+/// the loop on a V–I cadence; the menu is silent.
+fn play_music(
+    mut commands: Commands,
+    time: Res<Time>,
+    game_state: Res<GameState>,
+    arena: Res<Arena>,
+    bank: Res<MusicBank>,
+    settings: Res<AudioSettings>,
+    mut sequencer: Local<Sequencer>,
+    mut last_phase: Local<Option<GamePhase>>,
+) {
+    let entered = *last_phase != Some(game_state.phase);
+    *last_phase = Some(game_state.phase);
+    let volume = settings.effective_music_volume();
+
+    let (gain, score) = match game_state.phase {
+        GamePhase::Playing => {
+            if entered {
+                // Bank a full step so the downbeat lands immediately.
+                *sequencer = Sequencer {
+                    step: 0,
+                    elapsed: arena.move_interval,
+                };
+            }
+            (1.0, game_state.score)
+        }
+        // Score 0 strips the loop back to its bass line.
+        GamePhase::GameOver | GamePhase::TimeUp => (DUCKED_MUSIC, 0),
+        GamePhase::Won => {
+            if entered {
+                play(&mut commands, &bank.cadence, volume);
+            }
+            return;
+        }
+        GamePhase::Menu => return,
+    };
+
+    sequencer.elapsed += time.delta();
+    while sequencer.elapsed >= arena.move_interval {
+        sequencer.elapsed -= arena.move_interval;
+        for voice in music::step_voices(sequencer.step, score) {
+            play(&mut commands, bank.voice(voice), volume * gain);
+        }
+        sequencer.step += 1;
+    }
+}
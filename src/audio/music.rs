@@ -0,0 +1,178 @@
+//! Pure generative music: the chord progression, which layers play at a
+//! given score, which notes each sequencer step triggers, and the sample
+//! buffers for those notes.
+//!
+//! The music is a step sequencer — one step per snake move-tick — looping a
+//! four-bar minor progression. Layers join as the run goes on: bass from the
+//! first step, arpeggios at [`ARP_FROM_SCORE`], hi-hats at
+//! [`HATS_FROM_SCORE`].
+
+use super::synth;
+
+/// Steps per bar; each bar holds one chord of [`PROGRESSION`].
+pub const STEPS_PER_BAR: usize = 8;
+/// Score at which the arpeggio layer joins.
+pub const ARP_FROM_SCORE: usize = 10;
+/// Score at which the hi-hat layer joins.
+pub const HATS_FROM_SCORE: usize = 25;
+
+/// Chord roots of the loop (Am – F – C – G), in Hz at bass octave.
+pub const PROGRESSION: [f32; 4] = [110.0, 87.31, 130.81, 98.0];
+/// Chord quality per bar, as semitone offsets from the root: minor for Am,
+/// major for the rest.
+const CHORD_TONES: [[f32; 4]; 4] = [
+    [0.0, 3.0, 7.0, 12.0],
+    [0.0, 4.0, 7.0, 12.0],
+    [0.0, 4.0, 7.0, 12.0],
+    [0.0, 4.0, 7.0, 12.0],
+];
+/// Bass hits within a bar (a 3-3-2 pattern).
+const BASS_STEPS: [usize; 3] = [0, 3, 6];
+/// Arpeggio tone order within a bar, indexing [`CHORD_TONES`].
+const ARP_PATTERN: [usize; STEPS_PER_BAR] = [0, 1, 2, 3, 2, 1, 2, 3];
+
+/// One musical layer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Layer {
+    Bass,
+    Arp,
+    Hats,
+}
+
+/// A note to trigger on a sequencer step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Voice {
+    pub layer: Layer,
+    /// Index into [`PROGRESSION`].
+    pub chord: usize,
+    /// Index into the chord's tones (always 0 for bass and hats).
+    pub tone: usize,
+}
+
+/// Whether `layer` plays at `score`.
+pub fn layer_active(layer: Layer, score: usize) -> bool {
+    match layer {
+        Layer::Bass => true,
+        Layer::Arp => score >= ARP_FROM_SCORE,
+        Layer::Hats => score >= HATS_FROM_SCORE,
+    }
+}
+
+/// Every note triggered by sequencer `step` at `score`.
+pub fn step_voices(step: usize, score: usize) -> Vec<Voice> {
+    let chord = (step / STEPS_PER_BAR) % PROGRESSION.len();
+    let beat = step % STEPS_PER_BAR;
+    let mut voices = Vec::new();
+    if BASS_STEPS.contains(&beat) {
+        voices.push(Voice {
+            layer: Layer::Bass,
+            chord,
+            tone: 0,
+        });
+    }
+    if layer_active(Layer::Arp, score) {
+        voices.push(Voice {
+            layer: Layer::Arp,
+            chord,
+            tone: ARP_PATTERN[beat],
+        });
+    }
+    if layer_active(Layer::Hats, score) && beat % 2 == 1 {
+        voices.push(Voice {
+            layer: Layer::Hats,
+            chord,
+            tone: 0,
+        });
+    }
+    voices
+}
+
+/// Frequency of `tone` in `chord`, `octaves` above the bass register.
+fn chord_tone(chord: usize, tone: usize, octaves: f32) -> f32 {
+    synth::semitones(
+        PROGRESSION[chord],
+        CHORD_TONES[chord][tone] + octaves * 12.0,
+    )
+}
+
+/// A bass note for `chord`, lasting about two steps.
+pub fn bass_note(chord: usize, step_secs: f32) -> Vec<f32> {
+    let hz = chord_tone(chord, 0, 0.0);
+    synth::gain(synth::tone(hz, hz, step_secs * 2.0, 3.0), 0.6)
+}
+
+/// An arpeggio note: `tone` of `chord` two octaves up, one step long.
+pub fn arp_note(chord: usize, tone: usize, step_secs: f32) -> Vec<f32> {
+    let hz = chord_tone(chord, tone, 2.0);
+    synth::gain(synth::tone(hz, hz, step_secs, 5.0), 0.25)
+}
+
+/// A closed hi-hat: a very short, bright noise tick.
+pub fn hat() -> Vec<f32> {
+    synth::gain(synth::noise_burst(0.04, 9.0, 0.9), 0.2)
+}
+
+/// The win cadence: E major (V) resolving to A major (I, a Picardy third
+/// over the minor loop), each as a bass root under a three-note chord.
+pub fn cadence() -> Vec<f32> {
+    let chord = |root: f32, secs: f32| {
+        let layers: Vec<Vec<f32>> = [0.0, 4.0, 7.0, 12.0]
+            .iter()
+            .enumerate()
+            .map(|(i, &step)| {
+                let hz = synth::semitones(root, step + if i == 0 { 0.0 } else { 12.0 });
+                synth::gain(synth::tone(hz, hz, secs, 2.5), 0.3)
+            })
+            .collect();
+        let layers: Vec<&[f32]> = layers.iter().map(Vec::as_slice).collect();
+        synth::mix(&layers)
+    };
+    let mut cadence = chord(82.41, 0.5); // E2
+    cadence.extend(chord(110.0, 1.4)); // A2
+    cadence
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layers_at(score: usize) -> Vec<Layer> {
+        let mut layers: Vec<Layer> = (0..STEPS_PER_BAR * PROGRESSION.len())
+            .flat_map(|step| step_voices(step, score))
+            .map(|voice| voice.layer)
+            .collect();
+        layers.sort_by_key(|layer| *layer as usize);
+        layers.dedup();
+        layers
+    }
+
+    #[test]
+    fn layers_build_up_with_score() {
+        assert_eq!(layers_at(0), [Layer::Bass]);
+        assert_eq!(layers_at(ARP_FROM_SCORE), [Layer::Bass, Layer::Arp]);
+        assert_eq!(
+            layers_at(HATS_FROM_SCORE),
+            [Layer::Bass, Layer::Arp, Layer::Hats]
+        );
+    }
+
+    #[test]
+    fn progression_loops() {
+        let bars = STEPS_PER_BAR * PROGRESSION.len();
+        for step in 0..bars {
+            assert_eq!(step_voices(step, 30), step_voices(step + bars, 30));
+        }
+    }
+
+    #[test]
+    fn note_buffers_stay_in_range() {
+        for chord in 0..PROGRESSION.len() {
+            let mut buffers = vec![bass_note(chord, 0.15)];
+            buffers.extend((0..4).map(|tone| arp_note(chord, tone, 0.15)));
+            for samples in buffers {
+                assert!(samples.iter().all(|s| s.abs() <= 1.0));
+            }
+        }
+        assert!(cadence().iter().all(|s| s.abs() <= 1.0));
+    }
+}
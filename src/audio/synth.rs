@@ -0,0 +1,191 @@
+//! Pure sound synthesis: every function returns a mono `f32` sample buffer
+//! in `[-1.0, 1.0]` at [`SAMPLE_RATE`], with no Bevy types involved.
+//!
+//! [`encode_wav`] wraps a buffer in a 16-bit PCM WAV container so Bevy's
+//! regular `AudioSource` decoder can play it.
+
+use std::f32::consts::TAU;
+
+/// Sample rate of every synthesized buffer. Plenty for blips and thuds, and
+/// keeps the startup synthesis cost negligible.
+pub const SAMPLE_RATE: u32 = 22_050;
+
+/// Number of samples in `secs` seconds of audio.
+fn sample_count(secs: f32) -> usize {
+    (secs * SAMPLE_RATE as f32).round() as usize
+}
+
+/// Frequency `semitones` above (or below, if negative) `base`.
+pub fn semitones(base: f32, semitones: f32) -> f32 {
+    base * 2f32.powf(semitones / 12.0)
+}
+
+/// A sine tone gliding exponentially from `start_hz` to `end_hz`, with a
+/// short linear attack and an exponential decay so it never clicks.
+pub fn tone(start_hz: f32, end_hz: f32, secs: f32, decay: f32) -> Vec<f32> {
+    let len = sample_count(secs);
+    let attack = sample_count(0.004).max(1);
+    let mut phase = 0.0f32;
+    (0..len)
+        .map(|i| {
+            let t = i as f32 / len as f32;
+            let hz = start_hz * (end_hz / start_hz).powf(t);
+            phase = (phase + hz / SAMPLE_RATE as f32).fract();
+            let envelope = (i as f32 / attack as f32).min(1.0) * (-decay * t).exp();
+            (phase * TAU).sin() * envelope
+        })
+        .collect()
+}
+
+/// Deterministic white noise (xorshift32) with an exponential decay, run
+/// through a one-pole low-pass so it reads as a thud rather than a hiss.
+/// `smoothing` is the low-pass coefficient in `(0, 1]`; lower is duller.
+pub fn noise_burst(secs: f32, decay: f32, smoothing: f32) -> Vec<f32> {
+    let len = sample_count(secs);
+    let mut state = 0x9e37_79b9u32;
+    let mut filtered = 0.0f32;
+    (0..len)
+        .map(|i| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            let white = state as f32 / u32::MAX as f32 * 2.0 - 1.0;
+            filtered += smoothing * (white - filtered);
+            let t = i as f32 / len as f32;
+            filtered * (-decay * t).exp()
+        })
+        .collect()
+}
+
+/// Sums `layers` sample-by-sample; the result is as long as the longest
+/// layer and is clamped back into `[-1.0, 1.0]`.
+pub fn mix(layers: &[&[f32]]) -> Vec<f32> {
+    let len = layers.iter().map(|layer| layer.len()).max().unwrap_or(0);
+    (0..len)
+        .map(|i| {
+            let sum: f32 = layers.iter().filter_map(|layer| layer.get(i)).sum();
+            sum.clamp(-1.0, 1.0)
+        })
+        .collect()
+}
+
+/// Plays `notes` back to back, each `note_secs` long.
+pub fn sequence(notes: &[f32], note_secs: f32, decay: f32) -> Vec<f32> {
+    notes
+        .iter()
+        .flat_map(|&hz| tone(hz, hz, note_secs, decay))
+        .collect()
+}
+
+/// Scales every sample by `gain`.
+pub fn gain(mut samples: Vec<f32>, gain: f32) -> Vec<f32> {
+    samples.iter_mut().for_each(|sample| *sample *= gain);
+    samples
+}
+
+/// The apple-eaten blip: a quick upward chirp that climbs a major-scale
+/// step per `combo` level.
+pub fn eat(combo: usize) -> Vec<f32> {
+    const MAJOR_SCALE: [f32; 7] = [0.0, 2.0, 4.0, 5.0, 7.0, 9.0, 11.0];
+    let octave = (combo / MAJOR_SCALE.len()) as f32 * 12.0;
+    let step = MAJOR_SCALE[combo % MAJOR_SCALE.len()];
+    let hz = semitones(660.0, octave + step);
+    gain(tone(hz, hz * 1.5, 0.09, 4.0), 0.5)
+}
+
+/// The turn tick: a very short, soft low click.
+pub fn turn() -> Vec<f32> {
+    gain(tone(320.0, 220.0, 0.03, 6.0), 0.25)
+}
+
+/// The game-over thud: a low pitch-dropping sine under a dull noise burst.
+pub fn death() -> Vec<f32> {
+    let body = tone(110.0, 38.0, 0.45, 5.0);
+    let crunch = gain(noise_burst(0.3, 7.0, 0.18), 0.8);
+    gain(mix(&[&body, &crunch]), 0.8)
+}
+
+/// The win fanfare: a rising major arpeggio landing on the octave.
+pub fn win() -> Vec<f32> {
+    let root = 523.25; // C5
+    let notes = [0.0, 4.0, 7.0, 12.0].map(|step| semitones(root, step));
+    let mut fanfare = sequence(&notes[..3], 0.1, 2.5);
+    fanfare.extend(tone(notes[3], notes[3], 0.45, 3.0));
+    gain(fanfare, 0.5)
+}
+
+/// The Time Attack buzzer: two falling tones.
+pub fn time_up() -> Vec<f32> {
+    let notes = [semitones(440.0, 7.0), 440.0];
+    gain(sequence(&notes, 0.16, 3.0), 0.5)
+}
+
+/// Encodes `samples` as a mono 16-bit PCM WAV file at [`SAMPLE_RATE`].
+pub fn encode_wav(samples: &[f32]) -> Vec<u8> {
+    const HEADER_LEN: usize = 44;
+    let data_len = (samples.len() * 2) as u32;
+    let mut wav = Vec::with_capacity(HEADER_LEN + data_len as usize);
+
+    wav.extend_from_slice(b"RIFF");
+    wav.extend_from_slice(&(36 + data_len).to_le_bytes());
+    wav.extend_from_slice(b"WAVE");
+
+    wav.extend_from_slice(b"fmt ");
+    wav.extend_from_slice(&16u32.to_le_bytes()); // fmt chunk size
+    wav.extend_from_slice(&1u16.to_le_bytes()); // PCM
+    wav.extend_from_slice(&1u16.to_le_bytes()); // mono
+    wav.extend_from_slice(&SAMPLE_RATE.to_le_bytes());
+    wav.extend_from_slice(&(SAMPLE_RATE * 2).to_le_bytes()); // byte rate
+    wav.extend_from_slice(&2u16.to_le_bytes()); // block align
+    wav.extend_from_slice(&16u16.to_le_bytes()); // bits per sample
+
+    wav.extend_from_slice(b"data");
+    wav.extend_from_slice(&data_len.to_le_bytes());
+    for &sample in samples {
+        let pcm = (sample.clamp(-1.0, 1.0) * i16::MAX as f32) as i16;
+        wav.extend_from_slice(&pcm.to_le_bytes());
+    }
+    wav
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn buffers_stay_in_range() {
+        for samples in [eat(0), eat(20), turn(), death(), win(), time_up()] {
+            assert!(!samples.is_empty());
+            assert!(samples.iter().all(|s| s.is_finite() && s.abs() <= 1.0));
+        }
+    }
+
+    #[test]
+    fn eat_pitch_rises_with_combo() {
+        // Count upward zero crossings as a cheap pitch estimate.
+        let crossings = |samples: &[f32]| {
+            samples
+                .windows(2)
+                .filter(|pair| pair[0] < 0.0 && pair[1] >= 0.0)
+                .count()
+        };
+        assert!(crossings(&eat(0)) < crossings(&eat(3)));
+        assert!(crossings(&eat(3)) < crossings(&eat(7)));
+    }
+
+    #[test]
+    fn synthesis_is_deterministic() {
+        assert_eq!(death(), death());
+    }
+
+    #[test]
+    fn wav_header_matches_data() {
+        let samples = turn();
+        let wav = encode_wav(&samples);
+        assert_eq!(&wav[0..4], b"RIFF");
+        assert_eq!(&wav[8..12], b"WAVE");
+        assert_eq!(wav.len(), 44 + samples.len() * 2);
+        let data_len = u32::from_le_bytes(wav[40..44].try_into().unwrap());
+        assert_eq!(data_len as usize, samples.len() * 2);
+    }
+}
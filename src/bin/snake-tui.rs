@@ -0,0 +1,247 @@
+//! Terminal front-end: the same game as the Bevy build, played in a
+//! terminal with ANSI colors. Movement, growth, collisions and apple
+//! placement all come from `snake_bevy::rules`; this only reads keys and
+//! draws the board.
+//!
+//! ```text
+//! cargo run --bin snake-tui --no-default-features --features tui -- [--walls] [--size N]
+//! ```
+//!
+//! Arrow keys or WASD steer, SPACE starts a new run once one ends, Q or ESC
+//! quits. `--walls` makes the edges deadly instead of wrapping; `--size`
+//! plays on an N×N board.
+
+use std::io::{self, Write};
+use std::time::Instant;
+
+use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use crossterm::style::{Attribute, Color, Print, ResetColor, SetAttribute};
+use crossterm::style::{SetBackgroundColor, SetForegroundColor};
+use crossterm::terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen};
+use crossterm::{execute, queue};
+use snake_bevy::rules::{Board, Direction, FoodRng, Game, MOVE_INTERVAL, Outcome, Position};
+
+/// Smallest and largest `--size`: the Bevy game's smallest and largest arena.
+const SIZES: std::ops::RangeInclusive<u32> = 10..=25;
+
+// The Neon theme, flattened to terminal colors.
+const ARENA: Color = rgb(0.08, 0.08, 0.1);
+const ARENA_ALT: Color = rgb(0.095, 0.095, 0.12);
+const BORDER: Color = rgb(0.3, 0.5, 0.8);
+const SNAKE_HEAD: Color = rgb(0.6, 1.0, 0.6);
+const EYE: Color = rgb(0.02, 0.02, 0.02);
+const BODY_NEAR_HEAD: [f32; 3] = [0.40, 0.95, 0.40];
+const BODY_NEAR_TAIL: [f32; 3] = [0.08, 0.28, 0.08];
+const FOOD: Color = rgb(1.0, 0.3, 0.3);
+const TEXT: Color = rgb(0.9, 0.9, 0.9);
+
+/// Same curve as the Bevy body gradient: bright up front, fading faster
+/// toward the tail.
+const BODY_GRADIENT_EXPONENT: f32 = 1.6;
+
+const fn rgb(r: f32, g: f32, b: f32) -> Color {
+    Color::Rgb {
+        r: (r * 255.0) as u8,
+        g: (g * 255.0) as u8,
+        b: (b * 255.0) as u8,
+    }
+}
+
+/// Raw mode on the alternate screen with the cursor hidden, for as long as
+/// this lives.
+struct RawTerminal;
+
+impl RawTerminal {
+    fn enter() -> io::Result<Self> {
+        terminal::enable_raw_mode()?;
+        execute!(
+            io::stdout(),
+            EnterAlternateScreen,
+            Hide,
+            Clear(ClearType::All)
+        )?;
+        Ok(RawTerminal)
+    }
+}
+
+impl Drop for RawTerminal {
+    fn drop(&mut self) {
+        // Nothing sensible to do if restoring fails.
+        let _ = execute!(io::stdout(), ResetColor, Show, LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+fn main() {
+    let board = match parse_args(std::env::args().skip(1)) {
+        Ok(board) => board,
+        Err(message) => {
+            eprintln!("snake-tui: {message}");
+            std::process::exit(2);
+        }
+    };
+    if let Err(error) = run(board) {
+        eprintln!("snake-tui: {error}");
+        std::process::exit(1);
+    }
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Board, String> {
+    let mut board = Board::default();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--walls" => board.walls = true,
+            "--size" => {
+                let size = args
+                    .next()
+                    .and_then(|n| n.parse().ok())
+                    .filter(|n| SIZES.contains(n))
+                    .ok_or_else(|| format!("--size takes {} to {}", SIZES.start(), SIZES.end()))?;
+                board.width = size;
+                board.height = size;
+            }
+            _ => return Err(format!("unknown argument `{arg}`")),
+        }
+    }
+    Ok(board)
+}
+
+/// Plays runs on `board` until the player quits.
+fn run(board: Board) -> io::Result<()> {
+    let _terminal = RawTerminal::enter()?;
+    let mut out = io::stdout();
+    let mut game = Game::new(board, FoodRng::random());
+    let mut next_move = Instant::now() + MOVE_INTERVAL;
+
+    loop {
+        draw(&mut out, &game)?;
+
+        let timeout = next_move.saturating_duration_since(Instant::now());
+        if !event::poll(timeout)? {
+            game.tick();
+            // Never queue up a burst of moves after a stall.
+            next_move = Instant::now() + MOVE_INTERVAL;
+            continue;
+        }
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind == KeyEventKind::Release {
+            continue;
+        }
+        let ctrl_c = key.code == KeyCode::Char('c') && key.modifiers == KeyModifiers::CONTROL;
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => return Ok(()),
+            _ if ctrl_c => return Ok(()),
+            KeyCode::Char(' ') if game.outcome.is_some() => {
+                game = Game::new(board, FoodRng::random());
+                next_move = Instant::now() + MOVE_INTERVAL;
+            }
+            code => {
+                if let Some(direction) = direction_for(code) {
+                    game.steer(direction);
+                }
+            }
+        }
+    }
+}
+
+fn direction_for(code: KeyCode) -> Option<Direction> {
+    match code {
+        KeyCode::Up | KeyCode::Char('w' | 'W') => Some(Direction::Up),
+        KeyCode::Down | KeyCode::Char('s' | 'S') => Some(Direction::Down),
+        KeyCode::Left | KeyCode::Char('a' | 'A') => Some(Direction::Left),
+        KeyCode::Right | KeyCode::Char('d' | 'D') => Some(Direction::Right),
+        _ => None,
+    }
+}
+
+/// Draws the board framed by a border, two terminal columns per cell so the
+/// cells come out roughly square, with the score below.
+fn draw(out: &mut impl Write, game: &Game) -> io::Result<()> {
+    let Board { width, height, .. } = game.board;
+    let (width, height) = (width as i32, height as i32);
+
+    // Which snake part sits on each cell; after a self-bite the head wins.
+    let mut parts = vec![None; (width * height) as usize];
+    for (index, cell) in game.snake.iter().enumerate().rev() {
+        parts[(cell.y * width + cell.x) as usize] = Some(index);
+    }
+
+    let rule = "─".repeat(width as usize * 2);
+    queue!(
+        out,
+        MoveTo(0, 0),
+        SetForegroundColor(BORDER),
+        Print(format!("┌{rule}┐")),
+    )?;
+    // Row 0 is the bottom of the board, as in the Bevy game.
+    for (row, y) in (0..height).rev().enumerate() {
+        queue!(
+            out,
+            MoveTo(0, row as u16 + 1),
+            SetForegroundColor(BORDER),
+            Print("│")
+        )?;
+        for x in 0..width {
+            let cell = Position { x, y };
+            let floor = if (x + y) % 2 == 0 { ARENA } else { ARENA_ALT };
+            let (background, foreground, glyph) = match parts[(y * width + x) as usize] {
+                Some(0) => (SNAKE_HEAD, EYE, "••"),
+                Some(index) => (body_color(index, game.snake.len()), floor, "  "),
+                None if game.food == Some(cell) => (floor, FOOD, "()"),
+                None => (floor, floor, "  "),
+            };
+            queue!(
+                out,
+                SetBackgroundColor(background),
+                SetForegroundColor(foreground),
+                Print(glyph),
+            )?;
+        }
+        queue!(out, ResetColor, SetForegroundColor(BORDER), Print("│"))?;
+    }
+    queue!(
+        out,
+        MoveTo(0, height as u16 + 1),
+        Print(format!("└{rule}┘")),
+        MoveTo(0, height as u16 + 2),
+        SetForegroundColor(TEXT),
+        Clear(ClearType::UntilNewLine),
+    )?;
+
+    let score = format!("Score: {}  Length: {}", game.score, game.snake.len());
+    match game.outcome {
+        None => queue!(out, Print(format!("{score}  -  Q quits")))?,
+        Some(outcome) => {
+            let title = match outcome {
+                Outcome::Crashed => "GAME OVER",
+                Outcome::Won => "YOU WIN!",
+            };
+            queue!(
+                out,
+                SetAttribute(Attribute::Bold),
+                Print(title),
+                SetAttribute(Attribute::Reset),
+                SetForegroundColor(TEXT),
+                Print(format!("  {score}  -  SPACE plays again, Q quits")),
+            )?;
+        }
+    }
+    queue!(out, ResetColor)?;
+    out.flush()
+}
+
+/// The color of body part `index` (1 is right behind the head) of a snake
+/// `len` parts long.
+fn body_color(index: usize, len: usize) -> Color {
+    let t = if len > 2 {
+        ((index - 1) as f32 / (len - 2) as f32).powf(BODY_GRADIENT_EXPONENT)
+    } else {
+        0.0
+    };
+    let [r, g, b] =
+        std::array::from_fn(|i| BODY_NEAR_HEAD[i] + (BODY_NEAR_TAIL[i] - BODY_NEAR_HEAD[i]) * t);
+    rgb(r, g, b)
+}
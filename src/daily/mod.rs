@@ -0,0 +1,169 @@
+//! Daily challenge plugin - derives the day's food seed from the UTC date and
+//! records the one scored attempt each day gets.
+//!
+//! Dates are UTC so every player sees the same apple sequence on the same
+//! day regardless of time zone.
+
+use bevy::prelude::*;
+
+use crate::game::{
+    DailyRecords, DailyResult, DailyRun, GameMode, GamePhase, GameSet, GameState, SaveRequested,
+};
+
+/// How many days of Daily history are kept in the settings file.
+const HISTORY_DAYS: usize = 30;
+
+/// Plugin for Daily challenge bookkeeping.
+pub struct DailyPlugin;
+
+impl Plugin for DailyPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DailyRun>()
+            .add_systems(Update, track_daily_run.in_set(GameSet::Ui));
+    }
+}
+
+/// Today's UTC date as `YYYY-MM-DD`.
+pub fn today() -> String {
+    let (year, month, day) = civil_from_days(unix_seconds().div_euclid(86_400));
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+/// The food seed for a given `YYYY-MM-DD` date: a 64-bit FNV-1a hash of the
+/// date string, so the seed is stable across platforms and releases.
+pub fn seed_for(date: &str) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+    date.bytes().fold(OFFSET_BASIS, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+    })
+}
+
+/// Seconds since the Unix epoch. `SystemTime::now` panics on wasm32, so the
+/// web build asks the browser instead.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn unix_seconds() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs() as i64)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn unix_seconds() -> i64 {
+    (js_sys::Date::now() / 1000.0) as i64
+}
+
+/// Converts days since 1970-01-01 into a proleptic Gregorian
+/// `(year, month, day)` — Howard Hinnant's `civil_from_days`.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097); // [0, 146096]
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365; // [0, 399]
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100); // [0, 365]
+    let mp = (5 * doy + 2) / 153; // [0, 11], March-based
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = (if mp < 10 { mp + 3 } else { mp - 9 }) as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// Claims the day's scored attempt when a Daily run starts, and writes the
+/// final score into that day's history entry when the run ends.
+///
+/// The attempt is claimed at the *start* so quitting mid-run can't be used
+/// to fish for a better seed-day score.
+fn track_daily_run(
+    mut saves: MessageWriter<SaveRequested>,
+    game_state: Res<GameState>,
+    game_mode: Res<GameMode>,
+    mut daily_run: ResMut<DailyRun>,
+    mut records: ResMut<DailyRecords>,
+    mut last_phase: Local<Option<GamePhase>>,
+) {
+    if *last_phase == Some(game_state.phase) {
+        return;
+    }
+    let previous = last_phase.replace(game_state.phase);
+    if *game_mode != GameMode::Daily {
+        return;
+    }
+
+    match game_state.phase {
+        GamePhase::Playing => {
+            let date = today();
+            let scored = records.for_date(&date).is_none();
+            if scored {
+                records.history.push(DailyResult {
+                    date: date.clone(),
+                    score: 0,
+                });
+                let overflow = records.history.len().saturating_sub(HISTORY_DAYS);
+                records.history.drain(..overflow);
+                saves.write(SaveRequested);
+            }
+            *daily_run = DailyRun { date, scored };
+        }
+        GamePhase::GameOver | GamePhase::Won | GamePhase::TimeUp
+            if previous == Some(GamePhase::Playing) && daily_run.scored =>
+        {
+            let score = game_state.score;
+            if let Some(entry) = records
+                .history
+                .iter_mut()
+                .rev()
+                .find(|result| result.date == daily_run.date)
+            {
+                entry.score = score;
+                saves.write(SaveRequested);
+            }
+            info!("Daily {} recorded: {score}", daily_run.date);
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snake_bevy::rules::{Board, FoodRng};
+
+    #[test]
+    fn civil_from_days_matches_known_dates() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+        assert_eq!(civil_from_days(11_016), (2000, 2, 29));
+        assert_eq!(civil_from_days(20_088), (2024, 12, 31));
+        assert_eq!(civil_from_days(20_744), (2026, 10, 18));
+    }
+
+    #[test]
+    fn the_seed_of_a_date_never_changes() {
+        assert_eq!(seed_for("2026-10-18"), 0x37c8_3b3a_7a9f_01cb);
+        assert_ne!(seed_for("2026-10-18"), seed_for("2026-10-19"));
+    }
+
+    #[test]
+    fn a_daily_seed_replays_the_same_apples() {
+        let board = Board {
+            width: 20,
+            height: 20,
+            walls: false,
+        };
+        // Each apple joins the occupied cells, as a growing snake would.
+        let apples = |mut rng: FoodRng| {
+            let mut occupied = Vec::new();
+            for _ in 0..5 {
+                let apple = rng.place(&board, &occupied).expect("a free cell");
+                occupied.push(apple);
+            }
+            occupied
+        };
+        let seed = seed_for("2026-10-18");
+        assert_eq!(apples(FoodRng::daily(seed)), apples(FoodRng::daily(seed)));
+        assert_ne!(
+            apples(FoodRng::daily(seed)),
+            apples(FoodRng::daily(seed_for("2026-10-19")))
+        );
+    }
+}
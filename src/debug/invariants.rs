@@ -0,0 +1,61 @@
+//! Debug builds check the rules' invariants (`rules::check_invariants`)
+//! after every frame of play, and panic at the first frame that breaks one,
+//! so a desync between the systems shows up where it starts.
+
+use bevy::prelude::*;
+use snake_bevy::rules::{Board, check_invariants};
+
+use crate::game::{Arena, Food, GameMode, GamePhase, GameState, Position, ZenBite, ZenSettings};
+
+/// Plugin for the invariant checker. Does nothing in release builds.
+pub struct InvariantsPlugin;
+
+impl Plugin for InvariantsPlugin {
+    fn build(&self, app: &mut App) {
+        if cfg!(debug_assertions) {
+            // After `Update`, once every command its systems queued
+            // (growth, new apples, Zen cuts) has been applied.
+            app.add_systems(PostUpdate, assert_invariants);
+        }
+    }
+}
+
+/// Panics if the snake, the apple and the score break an invariant while a
+/// run is being played.
+fn assert_invariants(
+    game_state: Res<GameState>,
+    game_mode: Res<GameMode>,
+    zen: Res<ZenSettings>,
+    arena: Res<Arena>,
+    positions: Query<&Position>,
+    food: Query<&Position, With<Food>>,
+) {
+    if game_state.phase != GamePhase::Playing {
+        return;
+    }
+    let snake: Vec<Position> = game_state
+        .snake_segments
+        .iter()
+        .map(|&entity| {
+            *positions
+                .get(entity)
+                .expect("snake part without a Position")
+        })
+        .collect();
+    // Walls never apply in Zen, whatever the arena says (see `snake_movement`).
+    let zen_mode = *game_mode == GameMode::Zen;
+    let board = Board {
+        walls: arena.walls && !zen_mode,
+        ..arena.board()
+    };
+    let overlap_allowed = zen_mode && zen.on_self_bite == ZenBite::PassThrough;
+    // Every apple, or just the snake and score if there is none.
+    let apples = food.iter().copied().map(Some);
+    for apple in apples.chain([None]) {
+        if let Err(violation) =
+            check_invariants(&board, &snake, apple, game_state.score, overlap_allowed)
+        {
+            panic!("invariant broken: {violation:?}\nsnake: {snake:?}\napple: {apple:?}");
+        }
+    }
+}
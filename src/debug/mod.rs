@@ -0,0 +1,219 @@
+//! Debug overlay (F3): the game's logical state next to what is drawn, so a
+//! desync between the two is easy to spot.
+//!
+//! A panel in the bottom-left corner lists the cell under the cursor, the
+//! head's cell and the one it is moving from, the heading and the queued
+//! turns, how far the current move is along, the snake's part count against
+//! `GameState::snake_segments`, the apple RNG seed and the number of cells
+//! an apple could land on. On the board, every part's logical cell is
+//! outlined, as is the cell under the cursor.
+//!
+//! Debug builds also check the game's invariants every frame; see
+//! [`invariants`].
+
+mod invariants;
+
+pub use invariants::InvariantsPlugin;
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use bevy_vector_shapes::prelude::*;
+
+use crate::food::FoodRng;
+use crate::game::{
+    Arena, CELL_SIZE, DebugPanel, GameSet, GameState, InputBuffer, MoveProgress, Position,
+    PreviousPosition, SnakeHead, SnakeSegment, Z_ARENA_MASK,
+};
+
+/// Text and outline color of the overlay; a fixed color rather than a theme
+/// slot so it reads the same on every theme.
+const DEBUG_COLOR: Color = Color::srgb(1.0, 0.85, 0.1);
+/// Outline color of the head's logical cell.
+const HEAD_COLOR: Color = Color::srgb(1.0, 0.3, 0.9);
+/// Outline color of the cell under the cursor.
+const HOVER_COLOR: Color = Color::WHITE;
+/// Above the arena mask, so outlines are never hidden.
+const Z_DEBUG: f32 = Z_ARENA_MASK + 1.0;
+
+/// Plugin for the F3 debug overlay.
+pub struct DebugPlugin;
+
+impl Plugin for DebugPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(InvariantsPlugin)
+            .init_resource::<DebugOverlay>()
+            .add_systems(Startup, spawn_debug_panel)
+            .add_systems(
+                Update,
+                (
+                    toggle_debug_overlay,
+                    update_debug_panel,
+                    outline_logical_cells,
+                )
+                    .chain()
+                    .in_set(GameSet::Ui),
+            );
+    }
+}
+
+/// Whether the overlay is showing.
+#[derive(Resource, Default)]
+struct DebugOverlay {
+    visible: bool,
+}
+
+/// Everything the overlay reads.
+#[derive(SystemParam)]
+struct DebugView<'w, 's> {
+    game_state: Res<'w, GameState>,
+    arena: Res<'w, Arena>,
+    input_buffer: Res<'w, InputBuffer>,
+    move_progress: Res<'w, MoveProgress>,
+    food_rng: Res<'w, FoodRng>,
+    windows: Query<'w, 's, &'static Window, With<PrimaryWindow>>,
+    cameras: Query<'w, 's, (&'static Camera, &'static GlobalTransform), With<Camera2d>>,
+    heads: Query<
+        'w,
+        's,
+        (
+            &'static SnakeHead,
+            &'static Position,
+            &'static PreviousPosition,
+        ),
+    >,
+    parts: Query<
+        'w,
+        's,
+        (&'static Position, Has<SnakeHead>),
+        Or<(With<SnakeHead>, With<SnakeSegment>)>,
+    >,
+}
+
+impl DebugView<'_, '_> {
+    /// The arena cell under the mouse cursor, if any.
+    fn hovered_cell(&self) -> Option<Position> {
+        let window = self.windows.single().ok()?;
+        let cursor = window.cursor_position()?;
+        let (camera, camera_transform) = self.cameras.single().ok()?;
+        let point = camera.viewport_to_world_2d(camera_transform, cursor).ok()?;
+        self.arena.cell_at(point)
+    }
+
+    /// The panel's text, one fact per line.
+    fn report(&self) -> String {
+        let cell = |p: Position| format!("({}, {})", p.x, p.y);
+        let hovered = self.hovered_cell().map_or("-".to_string(), cell);
+        let (head, heading) = match self.heads.single() {
+            Ok((head, position, previous)) => (
+                format!("{} from {}", cell(*position), cell(previous.pos)),
+                format!("{:?}", head.direction),
+            ),
+            Err(_) => ("-".to_string(), "-".to_string()),
+        };
+        let queued: Vec<String> = self
+            .input_buffer
+            .queued()
+            .map(|direction| format!("{direction:?}"))
+            .collect();
+        let occupied: Vec<Position> = self.parts.iter().map(|(position, _)| *position).collect();
+        let free = self.arena.board().free_cells(&occupied).len();
+        [
+            "DEBUG (F3)".to_string(),
+            format!("cursor: {hovered}"),
+            format!("head: {head}"),
+            format!("heading: {heading}  queued: [{}]", queued.join(", ")),
+            format!("move: {:.0}%", self.move_progress.0 * 100.0),
+            format!(
+                "parts: {} entities / {} in GameState",
+                occupied.len(),
+                self.game_state.snake_segments.len()
+            ),
+            format!("food seed: {:#018x}", self.food_rng.seed()),
+            format!("free cells: {free}"),
+        ]
+        .join("\n")
+    }
+}
+
+/// The overlay's text panel, hidden until F3 is pressed.
+fn debug_panel() -> impl Scene {
+    bsn! {
+        DebugPanel
+        Node {
+            position_type: PositionType::Absolute,
+            left: { Val::Px(8.0) },
+            bottom: { Val::Px(8.0) },
+            padding: { UiRect::all(Val::Px(6.0)) },
+        }
+        BackgroundColor({ Color::srgba(0.0, 0.0, 0.0, 0.75) })
+        GlobalZIndex(10)
+        Visibility::Hidden
+        Text("")
+        TextFont {
+            font_size: { FontSize::Px(13.0) },
+        }
+        TextColor(DEBUG_COLOR)
+    }
+}
+
+fn spawn_debug_panel(mut commands: Commands) {
+    commands.spawn_scene(debug_panel());
+}
+
+/// Shows or hides the overlay on F3.
+fn toggle_debug_overlay(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut overlay: ResMut<DebugOverlay>,
+    mut panels: Query<&mut Visibility, With<DebugPanel>>,
+) {
+    if !keyboard_input.just_pressed(KeyCode::F3) {
+        return;
+    }
+    overlay.visible = !overlay.visible;
+    for mut visibility in &mut panels {
+        *visibility = if overlay.visible {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+}
+
+/// Refreshes the panel's text while the overlay is showing.
+fn update_debug_panel(
+    overlay: Res<DebugOverlay>,
+    view: DebugView,
+    mut panels: Query<&mut Text, With<DebugPanel>>,
+) {
+    if !overlay.visible {
+        return;
+    }
+    let Ok(mut text) = panels.single_mut() else {
+        return;
+    };
+    let report = view.report();
+    if text.0 != report {
+        *text = Text::from(report);
+    }
+}
+
+/// Outlines every snake part's logical cell — where the rules say it is,
+/// not where it is drawn mid-slide — and the cell under the cursor.
+fn outline_logical_cells(overlay: Res<DebugOverlay>, view: DebugView, mut painter: ShapePainter) {
+    if !overlay.visible {
+        return;
+    }
+    painter.reset();
+    painter.hollow = true;
+    painter.thickness = 1.5;
+    let cells = view.parts.iter().map(|(position, is_head)| {
+        let color = if is_head { HEAD_COLOR } else { DEBUG_COLOR };
+        (*position, color)
+    });
+    for (position, color) in cells.chain(view.hovered_cell().map(|p| (p, HOVER_COLOR))) {
+        painter.color = color;
+        painter.set_translation(view.arena.to_world(position).extend(Z_DEBUG));
+        painter.rect(Vec2::splat(CELL_SIZE));
+    }
+}
@@ -0,0 +1,519 @@
+//! Export plugin - saves the board as an SVG picture.
+//!
+//! The picture is drawn from the game's [`Position`]s rather than read back
+//! from the GPU, so it works anywhere, including CI and headless tools:
+//! build a [`BoardSnapshot`] and call [`BoardSnapshot::to_svg`]. In game,
+//! F12 (or an [`ExportBoard`] message) writes the current board to
+//! `snake-<unix seconds>.svg` in the working directory.
+//!
+//! Shapes and colors follow the renderer: the checkerboard, the apple with
+//! its highlight, stem and leaf, and the snake's tail taper and head→tail
+//! gradient (via [`body_segment_look`]), in either body style. Animation
+//! (breathing, blinking, interpolation between cells) is left out; the
+//! picture shows every part resting in its cell.
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+use crate::daily;
+use crate::game::{
+    AccessibilitySettings, Arena, BodyStyle, CELL_SIZE, Direction, ExportBoard, Food, GameMode,
+    GameSet, GameState, Position, RunClock, SnakeHead, ThemeSettings, WINDOW_PADDING,
+};
+use crate::locale::Locale;
+use crate::snake::{
+    HEAD_ROUNDING, HEAD_SIZE_FACTOR, OUTLINE_THICKNESS, SEGMENT_ROUNDING, SEGMENT_SIZE_FACTOR,
+    body_segment_look,
+};
+use crate::theme::Theme;
+use crate::ui::mode_label;
+
+/// Space around the arena, matching the game window's padding.
+const MARGIN: f32 = WINDOW_PADDING / 2.0;
+/// Height of the summary line under the arena.
+const CAPTION_HEIGHT: f32 = CELL_SIZE * 1.2;
+/// Font size of the summary line.
+const CAPTION_FONT_SIZE: f32 = 14.0;
+
+/// Plugin for the board export hotkey.
+pub struct ExportPlugin;
+
+impl Plugin for ExportPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (export_hotkey, export_board).chain().in_set(GameSet::Ui),
+        );
+    }
+}
+
+/// Everything an exported picture shows: the board and a one-line summary
+/// of the run.
+#[derive(Debug, Clone)]
+pub struct BoardSnapshot {
+    pub width: u32,
+    pub height: u32,
+    /// Whether the arena has deadly walls instead of wrap-around edges.
+    pub walls: bool,
+    /// Cells of the snake, head first. Empty on the menu.
+    pub snake: Vec<Position>,
+    pub heading: Direction,
+    pub food: Vec<Position>,
+    pub body_style: BodyStyle,
+    /// Flat body color with outlines, as in high-contrast mode.
+    pub high_contrast: bool,
+    /// Draw the apple's shape marker (see
+    /// [`AccessibilitySettings::marks_food`]).
+    pub mark_food: bool,
+    /// Shown under the arena, e.g. mode, score and time.
+    pub summary: String,
+}
+
+impl BoardSnapshot {
+    /// Center of `position`'s cell in picture coordinates (y down).
+    fn cell_center(&self, position: Position) -> Vec2 {
+        Vec2::new(
+            MARGIN + (position.x as f32 + 0.5) * CELL_SIZE,
+            MARGIN + (self.height as f32 - position.y as f32 - 0.5) * CELL_SIZE,
+        )
+    }
+
+    /// Renders the snapshot as a standalone SVG document.
+    pub fn to_svg(&self, theme: &Theme) -> String {
+        let arena = Vec2::new(self.width as f32, self.height as f32) * CELL_SIZE;
+        let size = arena + Vec2::new(2.0 * MARGIN, 2.0 * MARGIN + CAPTION_HEIGHT);
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" \
+             viewBox=\"0 0 {w} {h}\">\n",
+            w = size.x,
+            h = size.y,
+        );
+        svg.push_str(&format!(
+            "  <defs><clipPath id=\"arena\"><rect x=\"{MARGIN}\" y=\"{MARGIN}\" \
+             width=\"{}\" height=\"{}\"/></clipPath></defs>\n",
+            arena.x, arena.y,
+        ));
+        svg.push_str(&format!(
+            "  <rect width=\"{}\" height=\"{}\"{}/>\n",
+            size.x,
+            size.y,
+            fill(theme.background),
+        ));
+
+        // Arena and checkerboard, as `spawn_arena` lays them out.
+        svg.push_str(&format!(
+            "  <rect x=\"{MARGIN}\" y=\"{MARGIN}\" width=\"{}\" height=\"{}\"{}/>\n",
+            arena.x,
+            arena.y,
+            fill(theme.arena),
+        ));
+        for x in 0..self.width as i32 {
+            for y in 0..self.height as i32 {
+                if (x + y) % 2 == 0 {
+                    continue;
+                }
+                let corner = self.cell_center(Position { x, y }) - Vec2::splat(CELL_SIZE / 2.0);
+                svg.push_str(&format!(
+                    "  <rect x=\"{}\" y=\"{}\" width=\"{CELL_SIZE}\" height=\"{CELL_SIZE}\"{}/>\n",
+                    corner.x,
+                    corner.y,
+                    fill(theme.arena_alt),
+                ));
+            }
+        }
+        svg.push_str(&format!(
+            "  <rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" rx=\"2\" fill=\"none\" \
+             stroke-width=\"2\"{}/>\n",
+            MARGIN - 2.0,
+            MARGIN - 2.0,
+            arena.x + 4.0,
+            arena.y + 4.0,
+            stroke(theme.arena_border),
+        ));
+
+        svg.push_str("  <g clip-path=\"url(#arena)\">\n");
+        for &food in &self.food {
+            self.write_apple(&mut svg, food, theme);
+        }
+        match self.body_style {
+            BodyStyle::Pills => self.write_pills(&mut svg, theme),
+            BodyStyle::Tube => self.write_tube(&mut svg, theme),
+        }
+        if let Some(&head) = self.snake.first() {
+            self.write_head(&mut svg, head, theme);
+        }
+        svg.push_str("  </g>\n");
+
+        svg.push_str(&format!(
+            "  <text x=\"{}\" y=\"{}\" font-family=\"monospace\" font-size=\"{CAPTION_FONT_SIZE}\" \
+             font-weight=\"bold\" dominant-baseline=\"middle\"{}>{}</text>\n",
+            MARGIN,
+            MARGIN * 2.0 + arena.y + CAPTION_HEIGHT / 2.0,
+            fill(theme.text),
+            escape(&self.summary),
+        ));
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    /// The apple, mirroring `spawn_food`: stem and leaf behind a round body
+    /// with a glossy highlight.
+    fn write_apple(&self, svg: &mut String, position: Position, theme: &Theme) {
+        let Vec2 { x, y } = self.cell_center(position);
+        let radius = CELL_SIZE * 0.40;
+        svg.push_str(&format!("    <g transform=\"translate({x} {y})\">\n"));
+        svg.push_str(&format!(
+            "      <rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" rx=\"{}\" \
+             transform=\"translate(0 {}) rotate({})\"{}/>\n",
+            -radius * 0.09,
+            -radius * 0.275,
+            radius * 0.18,
+            radius * 0.55,
+            radius * 0.09,
+            -radius,
+            -(0.25_f32).to_degrees(),
+            fill(theme.apple_stem),
+        ));
+        svg.push_str(&format!(
+            "      <rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" rx=\"{}\" \
+             transform=\"translate({} {}) rotate({})\"{}/>\n",
+            -radius * 0.35,
+            -radius * 0.16,
+            radius * 0.7,
+            radius * 0.32,
+            radius * 0.16,
+            radius * 0.45,
+            -radius * 1.05,
+            -(0.9_f32).to_degrees(),
+            fill(theme.apple_leaf),
+        ));
+        svg.push_str(&format!(
+            "      <circle r=\"{radius}\"{}/>\n",
+            fill(theme.food)
+        ));
+        svg.push_str(&format!(
+            "      <circle cx=\"{}\" cy=\"{}\" r=\"{}\"{}/>\n",
+            -radius * 0.35,
+            -radius * 0.35,
+            radius * 0.26,
+            fill(theme.apple_highlight),
+        ));
+        if self.mark_food {
+            let side = radius * 1.7;
+            svg.push_str(&format!(
+                "      <rect x=\"{}\" y=\"{}\" width=\"{side}\" height=\"{side}\" fill=\"none\" \
+                 stroke-width=\"2\" transform=\"rotate(45)\"{}/>\n",
+                -side / 2.0,
+                -side / 2.0,
+                stroke(theme.outline()),
+            ));
+        }
+        svg.push_str("    </g>\n");
+    }
+
+    /// Resting size and color of the body part at `index` in
+    /// [`Self::snake`]. The head counts as the first body segment, which is
+    /// where the tube runs into it.
+    fn body_look(&self, index: usize, theme: &Theme) -> (f32, Color) {
+        let body_count = self.snake.len() - 1;
+        let (taper, color) =
+            body_segment_look(index.max(1) - 1, body_count, theme, self.high_contrast);
+        (CELL_SIZE * SEGMENT_SIZE_FACTOR * taper, color)
+    }
+
+    /// The classic body: one rounded square per segment, tail first so
+    /// segments nearer the head draw on top.
+    fn write_pills(&self, svg: &mut String, theme: &Theme) {
+        for (index, &position) in self.snake.iter().enumerate().skip(1).rev() {
+            let (size, color) = self.body_look(index, theme);
+            let corner = self.cell_center(position) - Vec2::splat(size / 2.0);
+            let outline = if self.high_contrast {
+                format!(
+                    " stroke-width=\"{OUTLINE_THICKNESS}\"{}",
+                    stroke(theme.outline())
+                )
+            } else {
+                String::new()
+            };
+            svg.push_str(&format!(
+                "    <rect x=\"{}\" y=\"{}\" width=\"{size}\" height=\"{size}\" \
+                 rx=\"{}\"{}{outline}/>\n",
+                corner.x,
+                corner.y,
+                SEGMENT_ROUNDING * size / 2.0,
+                fill(color),
+            ));
+        }
+    }
+
+    /// The tube body: a round-capped line per link, as `draw_snake_tube`
+    /// draws it. A link across a wrap-around edge is drawn from both ends
+    /// and clipped to the arena.
+    fn write_tube(&self, svg: &mut String, theme: &Theme) {
+        if self.snake.len() < 2 {
+            return;
+        }
+        let wrapped = |delta: i32, side: u32| {
+            let side = side as i32;
+            if !self.walls && delta.abs() > side / 2 {
+                delta - delta.signum() * side
+            } else {
+                delta
+            }
+        };
+        // High contrast traces the tube with the outline color underneath.
+        for outline in [true, false] {
+            if outline && !self.high_contrast {
+                continue;
+            }
+            for index in (1..self.snake.len()).rev() {
+                let (from, to) = (self.snake[index - 1], self.snake[index]);
+                let (width, color) = self.body_look(index, theme);
+                let delta = Vec2::new(
+                    wrapped(to.x - from.x, self.width) as f32,
+                    -(wrapped(to.y - from.y, self.height) as f32),
+                ) * CELL_SIZE;
+                let (start, end) = (self.cell_center(from), self.cell_center(to));
+                let mut links = vec![(start, start + delta)];
+                if start + delta != end {
+                    links.push((end - delta, end));
+                }
+                let (width, paint) = if outline {
+                    (width + 2.0 * OUTLINE_THICKNESS, stroke(theme.outline()))
+                } else {
+                    (width, stroke(color))
+                };
+                for (a, b) in links {
+                    svg.push_str(&format!(
+                        "    <line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\" \
+                         stroke-width=\"{width}\" stroke-linecap=\"round\"{paint}/>\n",
+                        a.x, a.y, b.x, b.y,
+                    ));
+                }
+            }
+        }
+    }
+
+    /// The head with its eyes, turned toward [`Self::heading`], mirroring
+    /// `spawn_snake_head`.
+    fn write_head(&self, svg: &mut String, position: Position, theme: &Theme) {
+        let Vec2 { x, y } = self.cell_center(position);
+        // Picture y points down, so rotations run clockwise.
+        let angle = match self.heading {
+            Direction::Right => 0.0,
+            Direction::Up => -90.0,
+            Direction::Left => 180.0,
+            Direction::Down => 90.0,
+        };
+        let size = CELL_SIZE * HEAD_SIZE_FACTOR;
+        svg.push_str(&format!(
+            "    <g transform=\"translate({x} {y}) rotate({angle})\">\n"
+        ));
+        svg.push_str(&format!(
+            "      <rect x=\"{}\" y=\"{}\" width=\"{size}\" height=\"{size}\" rx=\"{}\"{}/>\n",
+            -size / 2.0,
+            -size / 2.0,
+            HEAD_ROUNDING * size / 2.0,
+            fill(theme.snake_head),
+        ));
+        for side in [1.0_f32, -1.0] {
+            let (eye_x, eye_y) = (CELL_SIZE * 0.18, side * CELL_SIZE * 0.22);
+            svg.push_str(&format!(
+                "      <circle cx=\"{eye_x}\" cy=\"{eye_y}\" r=\"{}\"{}/>\n",
+                CELL_SIZE * 0.15,
+                fill(theme.eye),
+            ));
+            svg.push_str(&format!(
+                "      <circle cx=\"{}\" cy=\"{eye_y}\" r=\"{}\"{}/>\n",
+                eye_x + CELL_SIZE * 0.05,
+                CELL_SIZE * 0.085,
+                fill(theme.pupil),
+            ));
+        }
+        svg.push_str("    </g>\n");
+    }
+}
+
+/// `#rrggbb` for `color`. HDR colors are clamped to the displayable range.
+fn hex(color: Color) -> String {
+    let srgba = color.to_srgba();
+    let channel = |value: f32| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+    format!(
+        "#{:02x}{:02x}{:02x}",
+        channel(srgba.red),
+        channel(srgba.green),
+        channel(srgba.blue)
+    )
+}
+
+/// A `fill` attribute (with its opacity when not opaque).
+fn fill(color: Color) -> String {
+    paint("fill", color)
+}
+
+/// A `stroke` attribute (with its opacity when not opaque).
+fn stroke(color: Color) -> String {
+    paint("stroke", color)
+}
+
+fn paint(attribute: &str, color: Color) -> String {
+    let alpha = color.alpha().clamp(0.0, 1.0);
+    if alpha < 1.0 {
+        format!(
+            " {attribute}=\"{}\" {attribute}-opacity=\"{alpha}\"",
+            hex(color)
+        )
+    } else {
+        format!(" {attribute}=\"{}\"", hex(color))
+    }
+}
+
+/// Escapes `text` for use as SVG character data.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Everything a snapshot of the running game is taken from.
+#[derive(SystemParam)]
+struct BoardView<'w, 's> {
+    game_state: Res<'w, GameState>,
+    game_mode: Res<'w, GameMode>,
+    run_clock: Res<'w, RunClock>,
+    arena: Res<'w, Arena>,
+    theme_settings: Res<'w, ThemeSettings>,
+    accessibility: Res<'w, AccessibilitySettings>,
+    locale: Res<'w, Locale>,
+    positions: Query<'w, 's, &'static Position>,
+    heads: Query<'w, 's, &'static SnakeHead>,
+    food: Query<'w, 's, &'static Position, With<Food>>,
+}
+
+impl BoardView<'_, '_> {
+    /// The board as it is right now.
+    fn snapshot(&self) -> BoardSnapshot {
+        let snake: Vec<Position> = self
+            .game_state
+            .snake_segments
+            .iter()
+            .filter_map(|&entity| self.positions.get(entity).ok().copied())
+            .collect();
+        let seconds = self.run_clock.elapsed.as_secs();
+        let summary = self.locale.format(
+            "export.summary",
+            &[
+                ("mode", &mode_label(*self.game_mode, &self.locale)),
+                ("score", &self.game_state.score),
+                ("length", &snake.len()),
+                ("time", &format!("{}:{:02}", seconds / 60, seconds % 60)),
+            ],
+        );
+        BoardSnapshot {
+            width: self.arena.width,
+            height: self.arena.height,
+            walls: self.arena.walls,
+            heading: self
+                .heads
+                .single()
+                .map_or(Direction::Right, |head| head.direction),
+            snake,
+            food: self.food.iter().copied().collect(),
+            body_style: self.theme_settings.body_style,
+            high_contrast: self.accessibility.high_contrast,
+            mark_food: self.accessibility.marks_food(),
+            summary,
+        }
+    }
+}
+
+/// Asks for an export on F12.
+fn export_hotkey(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut exports: MessageWriter<ExportBoard>,
+) {
+    if keyboard_input.just_pressed(KeyCode::F12) {
+        exports.write(ExportBoard);
+    }
+}
+
+/// Writes the board to an SVG file for each [`ExportBoard`] request (several
+/// in one frame make one file).
+fn export_board(mut requests: MessageReader<ExportBoard>, view: BoardView, theme: Res<Theme>) {
+    if requests.read().count() == 0 {
+        return;
+    }
+    let svg = view.snapshot().to_svg(&theme);
+    let path = format!("snake-{}.svg", daily::unix_seconds());
+    match std::fs::write(&path, svg) {
+        Ok(()) => info!("Saved the board to {path}"),
+        Err(error) => warn!("Couldn't save the board to {path}: {error}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(snake: Vec<Position>) -> BoardSnapshot {
+        BoardSnapshot {
+            width: 4,
+            height: 3,
+            walls: false,
+            snake,
+            heading: Direction::Right,
+            food: vec![Position { x: 3, y: 0 }],
+            body_style: BodyStyle::Pills,
+            high_contrast: false,
+            mark_food: false,
+            summary: "CLASSIC <3>".to_string(),
+        }
+    }
+
+    #[test]
+    fn svg_shows_board_snake_and_food() {
+        let theme = Theme::default();
+        let snake = vec![
+            Position { x: 2, y: 1 },
+            Position { x: 1, y: 1 },
+            Position { x: 0, y: 1 },
+        ];
+        let svg = snapshot(snake).to_svg(&theme);
+
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.trim_end().ends_with("</svg>"));
+        // Half of the 4x3 cells get the checkerboard tint.
+        let alt = format!(
+            "width=\"{CELL_SIZE}\" height=\"{CELL_SIZE}\"{}",
+            fill(theme.arena_alt)
+        );
+        assert_eq!(svg.matches(&alt).count(), 6);
+        // The body runs from the gradient's head color to its tail color.
+        assert!(svg.contains(&fill(theme.body_near_head)));
+        assert!(svg.contains(&fill(theme.body_near_tail)));
+        assert!(svg.contains(&fill(theme.snake_head)));
+        assert!(svg.contains(&fill(theme.food)));
+        assert!(svg.contains(&fill(theme.apple_leaf)));
+        assert!(svg.contains("CLASSIC &lt;3&gt;"));
+    }
+
+    #[test]
+    fn tube_links_across_a_wrapped_edge_are_drawn_from_both_ends() {
+        let theme = Theme::default();
+        let mut board = snapshot(vec![Position { x: 0, y: 1 }, Position { x: 3, y: 1 }]);
+        board.body_style = BodyStyle::Tube;
+        assert_eq!(board.to_svg(&theme).matches("<line").count(), 2);
+
+        board.snake = vec![Position { x: 1, y: 1 }, Position { x: 0, y: 1 }];
+        assert_eq!(board.to_svg(&theme).matches("<line").count(), 1);
+    }
+
+    #[test]
+    fn hex_clamps_hdr_colors() {
+        assert_eq!(hex(Color::srgb(2.0, 0.0, 1.0)), "#ff00ff");
+        assert_eq!(
+            fill(Color::srgba(0.0, 0.0, 0.0, 0.5)),
+            " fill=\"#000000\" fill-opacity=\"0.5\""
+        );
+    }
+}
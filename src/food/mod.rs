@@ -2,21 +2,24 @@
 
 use bevy::prelude::*;
 use bevy_vector_shapes::prelude::*;
-use rand::prelude::*;
 
 use crate::game::{
-    APPLE_HIGHLIGHT_COLOR, APPLE_LEAF_COLOR, APPLE_STEM_COLOR, ARENA_HEIGHT, ARENA_WIDTH,
-    CELL_SIZE, FOOD_COLOR, Food, FoodEatenEvent, FoodPulse, GamePhase, GameSet, GameState,
-    GrowthEvent, Position, PreviousPosition, SCORE_AREA_COLS, SCORE_AREA_ROWS, SnakeHead,
-    SnakeSegment, SpawnPop, Z_FOOD,
+    AccessibilitySettings, Arena, CELL_SIZE, Food, FoodEatenEvent, FoodPulse, GamePhase, GameSet,
+    GameState, GrowthEvent, Position, PreviousPosition, SnakeHead, SnakeSegment, SpawnPop,
+    ThemeColor, Z_FOOD,
 };
+use crate::theme::Theme;
+
+/// The apple-placement RNG is part of the game rules, so the terminal build
+/// places apples the same way.
+pub use snake_bevy::rules::FoodRng;
 
 /// Plugin for food-related systems.
 pub struct FoodPlugin;
 
 impl Plugin for FoodPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
+        app.init_resource::<FoodRng>().add_systems(
             Update,
             (food_collision, spawn_pop_animation, food_pulse_animation)
                 .chain()
@@ -38,56 +41,50 @@ type FoodPulseQuery<'w, 's> = Query<
 
 /// Spawns food at a random free cell that doesn't overlap the snake.
 ///
-/// Builds the complete set of occupied cells first, then picks uniformly from
-/// the complement. Returns `false` when the arena is completely full (no free
-/// cell exists), allowing the caller to transition to a win state. Returns
-/// `true` when a food entity was successfully spawned.
-pub fn spawn_food(commands: &mut Commands, snake_positions: &[Position]) -> bool {
-    let mut rng = rand::rng();
-
-    // Build a hash-set of occupied cells for O(1) lookup.
-    let occupied: std::collections::HashSet<Position> = snake_positions.iter().copied().collect();
-
-    // Collect every cell in the arena that is free.
-    // Exclude the top-left area where the score text is displayed (≈ 3 × 2 cells).
-    let free: Vec<Position> = (0..ARENA_WIDTH as i32)
-        .flat_map(|x| (0..ARENA_HEIGHT as i32).map(move |y| Position { x, y }))
-        .filter(|p| {
-            let is_score_area =
-                p.x < SCORE_AREA_COLS && p.y >= (ARENA_HEIGHT as i32 - SCORE_AREA_ROWS);
-            !occupied.contains(p) && !is_score_area
-        })
-        .collect();
-
+/// [`FoodRng`] picks the cell among those the snake leaves free (see
+/// `Board::free_cells`). Returns `false` when the arena is completely full
+/// (no free cell exists), allowing the caller to transition to a win state.
+/// Returns `true` when a food entity was successfully spawned.
+///
+/// With a color-vision palette or high contrast active, the apple is framed
+/// by a diamond so it is marked by shape as well as color.
+pub fn spawn_food(
+    commands: &mut Commands,
+    food_rng: &mut FoodRng,
+    arena: &Arena,
+    theme: &Theme,
+    accessibility: &AccessibilitySettings,
+    snake_positions: &[Position],
+) -> bool {
     // If every cell is occupied there is nowhere to place food — caller decides
     // what to do (e.g. transition to GamePhase::Won).
-    if free.is_empty() {
+    let Some(position) = food_rng.place(&arena.board(), snake_positions) else {
         return false;
-    }
-    let position = free[rng.random_range(0..free.len())];
+    };
 
     let radius = CELL_SIZE * 0.40;
 
     // Pre-compute world-space coordinates so the food spawns at its final
     // z-layer immediately (avoids a one-frame z=0 flash before the renderer
     // catches up next frame).
-    let world_x = (position.x as f32 - ARENA_WIDTH as f32 / 2.0 + 0.5) * CELL_SIZE;
-    let world_y = (position.y as f32 - ARENA_HEIGHT as f32 / 2.0 + 0.5) * CELL_SIZE;
+    let world = arena.to_world(position);
 
-    // The apple: HDR-red body with a glossy highlight, a stem, and a leaf.
+    // The apple: a round body with a glossy highlight, a stem, and a leaf.
+    // Every part is tagged with its theme slot so a theme switch repaints it.
     // Children ride the parent transform, so the pop-in / pulse animations
     // scale and wobble the whole fruit as one piece.
     commands
         .spawn((
             ShapeBundle::circle(
                 &ShapeConfig {
-                    color: FOOD_COLOR,
-                    transform: Transform::from_xyz(world_x, world_y, Z_FOOD),
+                    color: theme.food,
+                    transform: Transform::from_translation(world.extend(Z_FOOD)),
                     ..ShapeConfig::default_2d()
                 },
                 radius,
             ),
             Food,
+            ThemeColor::Food,
             position,
             PreviousPosition { pos: position },
             FoodPulse {
@@ -99,36 +96,62 @@ pub fn spawn_food(commands: &mut Commands, snake_positions: &[Position]) -> bool
         ))
         .with_children(|apple| {
             // Glossy highlight, upper-left.
-            apple.spawn(ShapeBundle::circle(
-                &ShapeConfig {
-                    color: APPLE_HIGHLIGHT_COLOR,
-                    transform: Transform::from_xyz(-radius * 0.35, radius * 0.35, 0.01),
-                    ..ShapeConfig::default_2d()
-                },
-                radius * 0.26,
+            apple.spawn((
+                ShapeBundle::circle(
+                    &ShapeConfig {
+                        color: theme.apple_highlight,
+                        transform: Transform::from_xyz(-radius * 0.35, radius * 0.35, 0.01),
+                        ..ShapeConfig::default_2d()
+                    },
+                    radius * 0.26,
+                ),
+                ThemeColor::AppleHighlight,
             ));
             // Stem: small brown pill poking out of the top, slightly tilted.
-            apple.spawn(ShapeBundle::rect(
-                &ShapeConfig {
-                    color: APPLE_STEM_COLOR,
-                    corner_radii: Vec4::splat(1.0),
-                    transform: Transform::from_xyz(0.0, radius * 1.0, -0.01)
-                        .with_rotation(Quat::from_rotation_z(0.25)),
-                    ..ShapeConfig::default_2d()
-                },
-                Vec2::new(radius * 0.18, radius * 0.55),
+            apple.spawn((
+                ShapeBundle::rect(
+                    &ShapeConfig {
+                        color: theme.apple_stem,
+                        corner_radii: Vec4::splat(1.0),
+                        transform: Transform::from_xyz(0.0, radius * 1.0, -0.01)
+                            .with_rotation(Quat::from_rotation_z(0.25)),
+                        ..ShapeConfig::default_2d()
+                    },
+                    Vec2::new(radius * 0.18, radius * 0.55),
+                ),
+                ThemeColor::AppleStem,
             ));
             // Leaf: green pill rotated off the stem.
-            apple.spawn(ShapeBundle::rect(
-                &ShapeConfig {
-                    color: APPLE_LEAF_COLOR,
-                    corner_radii: Vec4::splat(1.0),
-                    transform: Transform::from_xyz(radius * 0.45, radius * 1.05, -0.005)
-                        .with_rotation(Quat::from_rotation_z(0.9)),
-                    ..ShapeConfig::default_2d()
-                },
-                Vec2::new(radius * 0.7, radius * 0.32),
+            apple.spawn((
+                ShapeBundle::rect(
+                    &ShapeConfig {
+                        color: theme.apple_leaf,
+                        corner_radii: Vec4::splat(1.0),
+                        transform: Transform::from_xyz(radius * 0.45, radius * 1.05, -0.005)
+                            .with_rotation(Quat::from_rotation_z(0.9)),
+                        ..ShapeConfig::default_2d()
+                    },
+                    Vec2::new(radius * 0.7, radius * 0.32),
+                ),
+                ThemeColor::AppleLeaf,
             ));
+            // Shape marker: a hollow diamond around the fruit.
+            if accessibility.marks_food() {
+                apple.spawn((
+                    ShapeBundle::rect(
+                        &ShapeConfig {
+                            color: theme.outline(),
+                            hollow: true,
+                            thickness: 2.0,
+                            transform: Transform::from_xyz(0.0, 0.0, -0.02)
+                                .with_rotation(Quat::from_rotation_z(std::f32::consts::FRAC_PI_4)),
+                            ..ShapeConfig::default_2d()
+                        },
+                        Vec2::splat(radius * 1.7),
+                    ),
+                    ThemeColor::Outline,
+                ));
+            }
         });
     true
 }
@@ -161,14 +184,20 @@ fn spawn_pop_animation(
 ///
 /// If the arena is full after eating (no free cell to place new food), the
 /// game transitions to [`GamePhase::Won`].
+#[allow(clippy::too_many_arguments)]
 fn food_collision(
     mut commands: Commands,
     mut growth_writer: MessageWriter<GrowthEvent>,
     mut food_eaten_writer: MessageWriter<FoodEatenEvent>,
     mut game_state: ResMut<GameState>,
+    mut food_rng: ResMut<FoodRng>,
+    arena: Res<Arena>,
+    theme: Res<Theme>,
+    accessibility: Res<AccessibilitySettings>,
     head_positions: Query<&Position, With<SnakeHead>>,
     food_positions: Query<(Entity, &Position), With<Food>>,
     all_snake_positions: SnakePartsQuery,
+    previous_positions: Query<&PreviousPosition>,
 ) {
     if game_state.phase != GamePhase::Playing {
         return;
@@ -189,9 +218,23 @@ fn food_collision(
             food_eaten_writer.write(FoodEatenEvent { position: eaten_at });
             commands.entity(food_entity).despawn();
 
-            // Collect all snake positions to avoid spawning food on the snake.
-            let snake_positions: Vec<Position> = all_snake_positions.iter().copied().collect();
-            if !spawn_food(&mut commands, &snake_positions) {
+            // Collect all snake positions to avoid spawning food on the snake,
+            // plus the cell the tail just left: growth fills it this frame.
+            let vacated = game_state
+                .snake_segments
+                .last()
+                .and_then(|&tail| previous_positions.get(tail).ok())
+                .map(|previous| previous.pos);
+            let snake_positions: Vec<Position> =
+                all_snake_positions.iter().copied().chain(vacated).collect();
+            if !spawn_food(
+                &mut commands,
+                &mut food_rng,
+                &arena,
+                &theme,
+                &accessibility,
+                &snake_positions,
+            ) {
                 // No free cell remained — the snake fills the arena. Win!
                 game_state.phase = GamePhase::Won;
                 info!("You Win! Final score: {}", game_state.score);
@@ -203,9 +246,23 @@ fn food_collision(
 /// System to animate food with a gentle pulse and a playful wobble.
 ///
 /// Skips apples still in their `SpawnPop` intro so the two animations don't
-/// fight over `Transform::scale`.
-fn food_pulse_animation(time: Res<Time>, mut foods: FoodPulseQuery) {
+/// fight over `Transform::scale`. With reduced motion on, the apple sits
+/// still.
+fn food_pulse_animation(
+    time: Res<Time>,
+    accessibility: Res<AccessibilitySettings>,
+    mut foods: FoodPulseQuery,
+) {
     for (mut transform, mut pulse) in foods.iter_mut() {
+        if accessibility.reduce_motion {
+            // Only touch an apple still caught mid-wobble, so a resting one
+            // isn't marked changed every frame.
+            if transform.scale != Vec3::ONE || transform.rotation != Quat::IDENTITY {
+                transform.scale = Vec3::ONE;
+                transform.rotation = Quat::IDENTITY;
+            }
+            continue;
+        }
         pulse.timer.tick(time.delta());
 
         let angle = pulse.timer.fraction() * std::f32::consts::TAU;
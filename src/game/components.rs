@@ -2,16 +2,9 @@
 
 use bevy::prelude::*;
 
-/// Grid position component for entities on the arena.
-#[derive(Component, Clone, Copy, PartialEq, Eq, Hash, Debug)]
-pub struct Position {
-    pub x: i32,
-    pub y: i32,
-}
-
-/// Initial spawn cell for the snake's head. Lives next to `Position` so the
-/// constants module doesn't need to import a component type.
-pub const INITIAL_SNAKE_POSITION: Position = Position { x: 3, y: 3 };
+// `Position` and `Direction` come from the game rules, which the terminal
+// build shares; with the `gui` feature `Position` is a component.
+pub use snake_bevy::rules::{Direction, INITIAL_SNAKE_POSITION, Position};
 
 /// Component to track previous position for smooth interpolation.
 #[derive(Component, Clone, Copy, Debug)]
@@ -19,27 +12,6 @@ pub struct PreviousPosition {
     pub pos: Position,
 }
 
-/// Direction enum for snake movement.
-#[derive(PartialEq, Eq, Hash, Copy, Clone, Debug)]
-pub enum Direction {
-    Left,
-    Right,
-    Up,
-    Down,
-}
-
-impl Direction {
-    /// Returns the opposite direction.
-    pub fn opposite(&self) -> Self {
-        match self {
-            Direction::Left => Direction::Right,
-            Direction::Right => Direction::Left,
-            Direction::Up => Direction::Down,
-            Direction::Down => Direction::Up,
-        }
-    }
-}
-
 /// Component to mark the snake's head.
 #[derive(Component)]
 pub struct SnakeHead {
@@ -72,6 +44,14 @@ pub struct PulseEffect {
     pub end_scale: f32,
 }
 
+/// The reduced-motion stand-in for [`PulseEffect`]: holds still and fades
+/// out from `start_alpha` over `timer`.
+#[derive(Component)]
+pub struct FadeEffect {
+    pub timer: Timer,
+    pub start_alpha: f32,
+}
+
 /// Component for animating newly grown segments.
 #[derive(Component)]
 pub struct GrowingSegment {
@@ -84,7 +64,8 @@ pub struct SpawnPop {
     pub timer: Timer,
 }
 
-/// A short-lived particle from the food-eaten burst.
+/// A short-lived particle from the food-eaten burst or the snake's death
+/// burst.
 #[derive(Component)]
 pub struct Particle {
     pub velocity: Vec2,
@@ -104,22 +85,164 @@ pub struct SnakeTongue {
     pub side: f32,
 }
 
+/// Which [`crate::theme::Theme`] color an entity is painted with, so a
+/// theme switch can repaint it in place (see `restyle_world`). Works on
+/// shapes (`ShapeFill`), sprites, and text.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum ThemeColor {
+    Background,
+    Arena,
+    ArenaAlt,
+    ArenaBorder,
+    SnakeHead,
+    Eye,
+    Pupil,
+    Tongue,
+    Food,
+    AppleHighlight,
+    AppleStem,
+    AppleLeaf,
+    #[default]
+    Text,
+    Record,
+    /// High-contrast outlines and the apple's shape marker; see
+    /// `Theme::outline`.
+    Outline,
+}
+
+/// Component to mark the arena background, checkerboard, border and
+/// outside mask, which are rebuilt whenever the [`crate::game::Arena`]
+/// changes.
+#[derive(Component)]
+pub struct ArenaTile;
+
+/// A copy of a snake part drawn one arena width / height away while the
+/// part slides across a wrap-around edge, so it enters the opposite side as
+/// it leaves this one. Kept by `sync_wrap_ghosts` for as long as its part
+/// stays in the edge band.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct WrapGhost {
+    /// The snake part this copies.
+    pub source: Entity,
+    /// Where the copy is drawn, relative to the part.
+    pub offset: Vec2,
+}
+
+/// A segment's high-contrast outline (child of the segment), shown only
+/// while high contrast is on.
+#[derive(Component)]
+pub struct SegmentOutline;
+
 // The UI markers below derive `Default + Clone` in addition to `Component`
 // because they are spawned through `bsn!` scenes, whose template machinery
 // requires both.
 
-/// Component to mark the score display UI element.
+/// Component to mark the root of the in-game HUD (score, countdown).
+#[derive(Component, Default, Clone)]
+pub struct HudUI;
+
+/// Which run statistic a HUD text shows.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum HudText {
+    #[default]
+    Score,
+    /// The best score of the mode, counting the current run once it beats
+    /// the stored record.
+    Best,
+    Length,
+    /// Time played this run.
+    Clock,
+    Speed,
+}
+
+/// The HUD's board-fill bar: the full-width track and the fill inside it.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum FillBar {
+    #[default]
+    Track,
+    Fill,
+}
+
+/// Component to mark the Time Attack countdown in the HUD.
+#[derive(Component, Default, Clone)]
+pub struct TimerText;
+
+/// Component to mark the F3 debug overlay's text panel.
+#[derive(Component, Default, Clone)]
+pub struct DebugPanel;
+
+/// Component to mark the F2 performance HUD's panel.
+#[derive(Component, Default, Clone)]
+pub struct PerfPanel;
+
+/// Component to mark the performance HUD's numbers.
 #[derive(Component, Default, Clone)]
-pub struct ScoreText;
+pub struct PerfText;
+
+/// One bar of the performance HUD's frame-time graph; `index` 0 is the
+/// oldest frame shown.
+#[derive(Component, Default, Clone)]
+pub struct FrameTimeBar {
+    pub index: usize,
+}
+
+/// The performance HUD's bar for the `index`-th timed [`super::GameSet`].
+#[derive(Component, Default, Clone)]
+pub struct SetTimeBar {
+    pub index: usize,
+}
+
+/// A snake part the death sequence has burst. It is hidden rather than
+/// despawned, so `GameState::snake_segments` still describes the board as it
+/// was at death (F3 overlay, SVG export) until the run is cleared.
+#[derive(Component)]
+pub struct Burst;
+
+/// The red glow left on the cell where the snake died, until the board is
+/// cleared.
+#[derive(Component)]
+pub struct DeathHighlight;
 
 /// Component to mark the game over overlay UI.
 #[derive(Component, Default, Clone)]
 pub struct GameOverUI;
 
+/// Fades an overlay and everything on it in from transparent over `timer`.
+#[derive(Component, Default, Clone)]
+pub struct OverlayFadeIn {
+    pub timer: Timer,
+}
+
 /// Component to mark the win-screen overlay UI.
 #[derive(Component, Default, Clone)]
 pub struct WinUI;
 
+/// Component to mark the time-up overlay UI (Time Attack).
+#[derive(Component, Default, Clone)]
+pub struct TimeUpUI;
+
+/// The run summary panel of an end screen, hidden until toggled.
+#[derive(Component, Default, Clone)]
+pub struct RunSummaryPanel;
+
+/// One column of the run summary's score sparkline.
+#[derive(Component, Default, Clone)]
+pub struct SparkBar;
+
+/// A mutually exclusive choice button (e.g. the mode selector).
+/// `button_feedback` gives the selected one a brighter resting background.
+#[derive(Component, Default, Clone)]
+pub struct ChoiceButton {
+    pub selected: bool,
+}
+
 /// Component to mark the start menu UI.
 #[derive(Component, Default, Clone)]
 pub struct MenuUI;
+
+/// Component to mark an achievement-unlock toast. The toast slides in from
+/// the right edge, holds, then fades out and despawns when `timer` finishes.
+#[derive(Component, Default, Clone)]
+pub struct AchievementToast {
+    pub timer: Timer,
+}
@@ -2,7 +2,7 @@
 
 use bevy::prelude::*;
 
-use super::Position;
+use super::{Direction, GameMode, MenuScreen, Position, ZenBite};
 
 /// Message triggered when snake should grow.
 #[derive(Message)]
@@ -14,8 +14,109 @@ pub struct FoodEatenEvent {
     pub position: Position,
 }
 
+/// Message triggered when the head actually changes heading on a move-tick
+/// (a buffered direction was consumed and differs from the previous one).
+#[derive(Message)]
+pub struct TurnEvent {
+    pub direction: Direction,
+}
+
+/// What the snake ran into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeathKind {
+    /// Its own body, at the bitten `segment`, counting from the head (1 is
+    /// the neck).
+    SelfBite { segment: usize },
+    /// The edge of a walled arena.
+    Wall,
+}
+
+/// Message written the frame a collision ends the run, saying what the
+/// snake hit and where.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeathCause {
+    pub kind: DeathKind,
+    /// The bitten segment's cell, or the head's cell when it hit a wall.
+    pub cell: Position,
+}
+
 /// Message written by the UI action buttons (START / RESTART / PLAY AGAIN)
 /// requesting a new game. Handled by the same systems that handle the
 /// SPACE key, so buttons and keyboard share one start/restart code path.
-#[derive(Message)]
+#[derive(Message, Clone)]
 pub struct StartRequested;
+
+/// Message written by the end screens' MENU button (and ESC) asking to
+/// leave the finished run and return to the start menu.
+#[derive(Message, Clone)]
+pub struct ReturnToMenu;
+
+/// Message written by the end screens' SUMMARY button (and TAB) to show or
+/// hide the run summary.
+#[derive(Message, Clone)]
+pub struct ToggleRunSummary;
+
+/// Message written by the start menu's mode selector buttons.
+#[derive(Message, Clone)]
+pub struct SelectMode(pub GameMode);
+
+/// Message written by the start menu's Zen bite-rule buttons.
+#[derive(Message, Clone)]
+pub struct SetZenBite(pub ZenBite);
+
+/// One adjustable row of the settings screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Setting {
+    Speed,
+    ArenaSize,
+    Walls,
+    Language,
+    Theme,
+    BodyStyle,
+    Bloom,
+    Shake,
+    Particles,
+    ColorVision,
+    HighContrast,
+    ReduceMotion,
+    SoundVolume,
+    MusicVolume,
+    Muted,
+    Key(Direction),
+}
+
+/// Message written by the settings screen's buttons and by its keyboard /
+/// gamepad navigation.
+#[derive(Message, Clone)]
+pub enum AdjustSetting {
+    /// Pick the option at this index of a choice row.
+    Choose(Setting, usize),
+    /// Move a choice row or slider by this many steps.
+    Step(Setting, i32),
+    /// Start listening for a new key for a key binding row.
+    Rebind(Direction),
+}
+
+/// Message written by the menu's navigation buttons (SETTINGS / BACK ...)
+/// asking the menu to switch to another screen.
+#[derive(Message, Clone)]
+pub struct MenuNavigate(pub MenuScreen);
+
+/// Message written when a run satisfies an achievement for the first time.
+/// `index` points into [`crate::achievements::ACHIEVEMENTS`].
+#[derive(Message)]
+pub struct AchievementUnlocked {
+    pub index: usize,
+}
+
+/// Message asking for the board to be saved as an SVG picture (F12 in
+/// game). Write it from code to export without the hotkey.
+#[derive(Message, Clone)]
+pub struct ExportBoard;
+
+/// Message asking for the settings file (records, achievements and
+/// preferences) to be saved after a change. The game turns it into a
+/// `bevy_settings` save; the integration tests leave it unread, so they
+/// never touch the file.
+#[derive(Message, Clone)]
+pub struct SaveRequested;
@@ -3,11 +3,13 @@
 mod components;
 mod constants;
 mod events;
+mod plugin;
 mod resources;
 mod sets;
 
 pub use components::*;
 pub use constants::*;
 pub use events::*;
+pub use plugin::*;
 pub use resources::*;
 pub use sets::*;
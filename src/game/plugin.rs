@@ -0,0 +1,59 @@
+//! Shared game state: the frame's system-set order, and the resources and
+//! messages every gameplay plugin reads or writes.
+
+use bevy::prelude::*;
+
+use super::{
+    AchievementUnlocked, AdjustSetting, Arena, CameraShake, DeathCause, DeathSequence, ExportBoard,
+    FoodEatenEvent, GameMode, GameSet, GameState, GrowthEvent, InputBuffer, MenuNavigate,
+    MenuScreen, MoveProgress, ReturnToMenu, RunClock, RunStats, SaveRequested, SelectMode,
+    SetZenBite, StartRequested, ToggleRunSummary, TurnEvent,
+};
+
+/// Plugin registering the shared game state. Added before the gameplay
+/// plugins, both by the game and by the integration tests.
+pub struct GamePlugin;
+
+impl Plugin for GamePlugin {
+    fn build(&self, app: &mut App) {
+        // Enforce deterministic cross-plugin execution order every frame:
+        //   Movement → Collision → Effects → Rendering → Ui
+        app.configure_sets(
+            Update,
+            (
+                GameSet::Movement,
+                GameSet::Collision,
+                GameSet::Effects,
+                GameSet::Rendering,
+                GameSet::Ui,
+            )
+                .chain(),
+        )
+        // Resources
+        .init_resource::<GameState>()
+        .init_resource::<InputBuffer>()
+        .init_resource::<CameraShake>()
+        .init_resource::<DeathSequence>()
+        .init_resource::<MoveProgress>()
+        .init_resource::<MenuScreen>()
+        .init_resource::<GameMode>()
+        .init_resource::<RunClock>()
+        .init_resource::<RunStats>()
+        .init_resource::<Arena>()
+        // Events
+        .add_message::<GrowthEvent>()
+        .add_message::<FoodEatenEvent>()
+        .add_message::<DeathCause>()
+        .add_message::<StartRequested>()
+        .add_message::<TurnEvent>()
+        .add_message::<MenuNavigate>()
+        .add_message::<ReturnToMenu>()
+        .add_message::<ToggleRunSummary>()
+        .add_message::<SelectMode>()
+        .add_message::<SetZenBite>()
+        .add_message::<AdjustSetting>()
+        .add_message::<AchievementUnlocked>()
+        .add_message::<ExportBoard>()
+        .add_message::<SaveRequested>();
+    }
+}
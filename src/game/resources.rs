@@ -2,12 +2,15 @@
 
 use bevy::prelude::*;
 use bevy::settings::{ReflectSettingsGroup, SettingsGroup};
-use std::collections::VecDeque;
+use snake_bevy::rules::Board;
+use std::time::Duration;
 
-use super::Direction;
+use super::{
+    ARENA_HEIGHT, ARENA_WIDTH, CELL_SIZE, DEFAULT_VIEW_SIDE, DeathCause, Direction, HUD_HEIGHT,
+    MOVE_INTERVAL, Position,
+};
 
-/// Maximum number of direction changes that can be queued at once.
-pub const INPUT_BUFFER_CAPACITY: usize = 2;
+pub use snake_bevy::rules::InputBuffer;
 
 /// Game phase enum to track which state the game is in.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -18,6 +21,104 @@ pub enum GamePhase {
     GameOver,
     /// Player filled the entire arena with the snake — win condition.
     Won,
+    /// A Time Attack run's countdown reached zero with the snake alive.
+    TimeUp,
+}
+
+/// Time limits offered for [`GameMode::TimeAttack`]. Each keeps its own
+/// record in [`TimeAttackRecords`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeLimit {
+    OneMinute,
+    TwoMinutes,
+}
+
+impl TimeLimit {
+    /// Every limit, in selector order.
+    pub const ALL: [TimeLimit; 2] = [TimeLimit::OneMinute, TimeLimit::TwoMinutes];
+
+    /// The limit in seconds.
+    pub fn seconds(self) -> u32 {
+        match self {
+            TimeLimit::OneMinute => 60,
+            TimeLimit::TwoMinutes => 120,
+        }
+    }
+}
+
+/// Which rule set the current (or next) run is played under. Chosen on the
+/// start menu and fixed for the duration of a run.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GameMode {
+    /// Play until the snake dies or fills the arena.
+    #[default]
+    Classic,
+    /// Eat as many apples as possible before the countdown runs out.
+    TimeAttack { limit: TimeLimit },
+    /// Practice: biting yourself never ends the run (see [`ZenSettings`]),
+    /// and the score never counts toward any record.
+    Zen,
+    /// Daily challenge: apples follow a sequence seeded from today's date,
+    /// and only the first run of the day is scored (see [`DailyRecords`]).
+    Daily,
+}
+
+impl GameMode {
+    /// Every mode offered on the start menu, in selector order.
+    pub fn choices() -> Vec<GameMode> {
+        std::iter::once(GameMode::Classic)
+            .chain(
+                TimeLimit::ALL
+                    .into_iter()
+                    .map(|limit| GameMode::TimeAttack { limit }),
+            )
+            .chain([GameMode::Zen, GameMode::Daily])
+            .collect()
+    }
+
+    /// Catalogue key of the mode selector label. Time Attack's label takes
+    /// the limit as `{seconds}`.
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            GameMode::Classic => "mode.classic",
+            GameMode::TimeAttack { .. } => "mode.time_attack",
+            GameMode::Zen => "mode.zen",
+            GameMode::Daily => "mode.daily",
+        }
+    }
+
+    /// The run's time limit, if the mode has one.
+    pub fn time_limit(&self) -> Option<Duration> {
+        match self {
+            GameMode::Classic | GameMode::Zen | GameMode::Daily => None,
+            GameMode::TimeAttack { limit } => Some(Duration::from_secs(u64::from(limit.seconds()))),
+        }
+    }
+}
+
+/// Time spent in `Playing` during the current run. Reset when a run starts.
+#[derive(Resource, Default)]
+pub struct RunClock {
+    pub elapsed: Duration,
+}
+
+/// How the current (or just finished) run went, for the summary on its end
+/// screen. Reset when a run starts; kept by `crate::stats::StatsPlugin`.
+#[derive(Resource, Debug, Default, Clone)]
+pub struct RunStats {
+    /// Changes of heading.
+    pub turns: usize,
+    /// Most moves in a row without a turn.
+    pub longest_straight: usize,
+    /// Fewest safe cells the head could move on to after any move of the
+    /// run, or `None` before the first move.
+    pub closest_call: Option<usize>,
+    /// Apples eaten. Unlike the score, never cut back by a Zen bite.
+    pub apples: usize,
+    /// The score after each change, with the run time it changed at.
+    pub score_history: Vec<(Duration, usize)>,
+    /// What ended the run, if the snake died.
+    pub death: Option<DeathCause>,
 }
 
 /// Main game state resource.
@@ -49,37 +150,533 @@ pub struct HighScore {
     pub score: usize,
 }
 
-/// Input buffer to queue direction changes.
+/// Persistent best Time Attack scores, one per [`TimeLimit`]. Kept apart
+/// from [`HighScore`] so timed runs never compete with classic ones.
+#[derive(Resource, SettingsGroup, Reflect, Default)]
+#[reflect(Resource, SettingsGroup, Default)]
+pub struct TimeAttackRecords {
+    pub best_60: usize,
+    pub best_120: usize,
+}
+
+impl TimeAttackRecords {
+    /// Best score for the given time limit.
+    pub fn best(&self, limit: TimeLimit) -> usize {
+        match limit {
+            TimeLimit::OneMinute => self.best_60,
+            TimeLimit::TwoMinutes => self.best_120,
+        }
+    }
+
+    /// Mutable best score for the given time limit.
+    pub fn best_mut(&mut self, limit: TimeLimit) -> &mut usize {
+        match limit {
+            TimeLimit::OneMinute => &mut self.best_60,
+            TimeLimit::TwoMinutes => &mut self.best_120,
+        }
+    }
+}
+
+/// What happens when the head runs into the body in [`GameMode::Zen`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
+pub enum ZenBite {
+    /// The head slides over the body as if it weren't there.
+    #[default]
+    PassThrough,
+    /// The body is cut off at the bitten segment; the score shrinks with it.
+    CutTail,
+}
+
+/// Persistent Zen-mode preferences.
+#[derive(Resource, SettingsGroup, Reflect, Default)]
+#[reflect(Resource, SettingsGroup, Default)]
+pub struct ZenSettings {
+    pub on_self_bite: ZenBite,
+}
+
+/// One day's scored Daily challenge attempt.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Reflect)]
+pub struct DailyResult {
+    /// UTC date of the attempt, `YYYY-MM-DD`.
+    pub date: String,
+    pub score: usize,
+}
+
+/// Persistent Daily challenge history, oldest first. A day with an entry has
+/// used up its scored attempt.
+#[derive(Resource, SettingsGroup, Reflect, Default)]
+#[reflect(Resource, SettingsGroup, Default)]
+pub struct DailyRecords {
+    pub history: Vec<DailyResult>,
+}
+
+impl DailyRecords {
+    /// The scored result for `date`, if that day's attempt has been used.
+    pub fn for_date(&self, date: &str) -> Option<&DailyResult> {
+        self.history.iter().rev().find(|result| result.date == date)
+    }
+
+    /// Best score across every recorded day.
+    pub fn best(&self) -> usize {
+        self.history
+            .iter()
+            .map(|result| result.score)
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+/// The Daily challenge run in progress (or just finished), if any.
 #[derive(Resource, Default)]
-pub struct InputBuffer {
-    queued_directions: VecDeque<Direction>,
+pub struct DailyRun {
+    /// UTC date the run was started on — its seed and its history entry.
+    pub date: String,
+    /// Whether this run is the day's scored attempt.
+    pub scored: bool,
 }
 
-impl InputBuffer {
-    /// Queue a direction change. Drops the input if the buffer is full
-    /// (capacity is [`INPUT_BUFFER_CAPACITY`]).
-    pub fn queue_direction(&mut self, direction: Direction) {
-        if self.queued_directions.len() < INPUT_BUFFER_CAPACITY {
-            self.queued_directions.push_back(direction);
+/// Achievements unlocked so far, by [`crate::achievements::Achievement::id`].
+///
+/// Persisted next to [`HighScore`] in `settings.toml`. Ids rather than table
+/// indices are stored so reordering or extending the table never scrambles
+/// a player's unlocks.
+#[derive(Resource, SettingsGroup, Reflect, Default)]
+#[reflect(Resource, SettingsGroup, Default)]
+pub struct UnlockedAchievements {
+    pub ids: Vec<String>,
+}
+
+impl UnlockedAchievements {
+    /// Whether the achievement with this id has been unlocked.
+    pub fn contains(&self, id: &str) -> bool {
+        self.ids.iter().any(|unlocked| unlocked == id)
+    }
+}
+
+/// Persistent sound preferences. Volumes are linear, `0.0..=1.0`; `muted`
+/// silences effects and music alike.
+#[derive(Resource, SettingsGroup, Reflect)]
+#[reflect(Resource, SettingsGroup, Default)]
+pub struct AudioSettings {
+    pub volume: f32,
+    pub music_volume: f32,
+    pub muted: bool,
+}
+
+impl Default for AudioSettings {
+    fn default() -> Self {
+        AudioSettings {
+            volume: 0.7,
+            music_volume: 0.5,
+            muted: false,
         }
     }
+}
 
-    /// Pop the next queued direction.
-    pub fn pop_direction(&mut self) -> Option<Direction> {
-        self.queued_directions.pop_front()
+impl AudioSettings {
+    /// The gain sound effects should actually play at.
+    pub fn effective_volume(&self) -> f32 {
+        if self.muted {
+            0.0
+        } else {
+            self.volume.clamp(0.0, 1.0)
+        }
     }
 
-    /// Get the last queued direction without removing it.
-    pub fn last_direction(&self) -> Option<Direction> {
-        self.queued_directions.back().copied()
+    /// The gain background music should actually play at.
+    pub fn effective_music_volume(&self) -> f32 {
+        if self.muted {
+            0.0
+        } else {
+            self.music_volume.clamp(0.0, 1.0)
+        }
     }
+}
+
+/// Persistent display preferences.
+#[derive(Resource, SettingsGroup, Reflect)]
+#[reflect(Resource, SettingsGroup, Default)]
+pub struct ThemeSettings {
+    /// Name of the chosen [`crate::theme::Theme`]. Falls back to the default
+    /// theme if no theme by that name is loaded.
+    pub theme: String,
+    /// Pill chain or continuous tube.
+    pub body_style: BodyStyle,
+}
 
-    /// Clear all queued directions.
-    pub fn clear(&mut self) {
-        self.queued_directions.clear();
+impl Default for ThemeSettings {
+    fn default() -> Self {
+        ThemeSettings {
+            theme: "Neon".to_string(),
+            body_style: BodyStyle::default(),
+        }
+    }
+}
+
+/// How the snake's body is drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
+pub enum BodyStyle {
+    /// A chain of rounded segments with small gaps between them.
+    #[default]
+    Pills,
+    /// One smooth tube through the segment centers, rounded at the bends.
+    Tube,
+}
+
+impl BodyStyle {
+    /// Every style, in selector order.
+    pub const ALL: [BodyStyle; 2] = [BodyStyle::Pills, BodyStyle::Tube];
+
+    /// Catalogue key of the style selector label.
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            BodyStyle::Pills => "body.pills",
+            BodyStyle::Tube => "body.tube",
+        }
+    }
+}
+
+/// Persistent language choice.
+#[derive(Resource, SettingsGroup, Reflect)]
+#[reflect(Resource, SettingsGroup, Default)]
+pub struct LanguageSettings {
+    /// Code of the chosen [`crate::locale::Locale`] (`"en"`, `"nl"`, ...).
+    /// Falls back to English if no catalogue has that code.
+    pub language: String,
+}
+
+impl Default for LanguageSettings {
+    fn default() -> Self {
+        LanguageSettings {
+            language: "en".to_string(),
+        }
+    }
+}
+
+/// A color-vision-safe palette for the snake and the apple, layered over
+/// the active theme.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
+pub enum ColorVision {
+    /// The theme's own colors.
+    #[default]
+    Standard,
+    /// Red-green safe (green-weak): a blue snake and an orange apple.
+    Deuteranopia,
+    /// Red-green safe (red-weak): a blue snake and a yellow apple, since
+    /// reds read as dark.
+    Protanopia,
+    /// Blue-yellow safe: a bluish-green snake and a pink-red apple.
+    Tritanopia,
+}
+
+impl ColorVision {
+    /// Every palette, in selector order.
+    pub const ALL: [ColorVision; 4] = [
+        ColorVision::Standard,
+        ColorVision::Deuteranopia,
+        ColorVision::Protanopia,
+        ColorVision::Tritanopia,
+    ];
+
+    /// Catalogue key of the palette selector label.
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            ColorVision::Standard => "vision.standard",
+            ColorVision::Deuteranopia => "vision.deutan",
+            ColorVision::Protanopia => "vision.protan",
+            ColorVision::Tritanopia => "vision.tritan",
+        }
     }
 }
 
+/// Persistent accessibility options.
+#[derive(Resource, SettingsGroup, Reflect, Default)]
+#[reflect(Resource, SettingsGroup, Default)]
+pub struct AccessibilitySettings {
+    pub color_vision: ColorVision,
+    /// Strong outlines around the snake body and the apple, a tail that
+    /// never fades, and a plain arena without the checkerboard.
+    pub high_contrast: bool,
+    /// No camera shake, juice burst, flash, breathing body, or wobbling
+    /// apple; eating shows a gentle fade instead.
+    pub reduce_motion: bool,
+}
+
+impl AccessibilitySettings {
+    /// Whether the apple gets a shape marker so it never relies on color
+    /// alone.
+    pub fn marks_food(&self) -> bool {
+        self.color_vision != ColorVision::Standard || self.high_contrast
+    }
+}
+
+/// How fast the snake moves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
+pub enum Speed {
+    Slow,
+    #[default]
+    Normal,
+    Fast,
+}
+
+impl Speed {
+    /// Every speed, in selector order.
+    pub const ALL: [Speed; 3] = [Speed::Slow, Speed::Normal, Speed::Fast];
+
+    /// Catalogue key of the speed selector label.
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            Speed::Slow => "speed.slow",
+            Speed::Normal => "speed.normal",
+            Speed::Fast => "speed.fast",
+        }
+    }
+
+    /// Time between two snake moves.
+    pub fn move_interval(&self) -> Duration {
+        match self {
+            Speed::Slow => Duration::from_millis(200),
+            Speed::Normal => MOVE_INTERVAL,
+            Speed::Fast => Duration::from_millis(100),
+        }
+    }
+
+    /// The speed that moves the snake every `interval`, if any does.
+    pub fn from_move_interval(interval: Duration) -> Option<Speed> {
+        Speed::ALL
+            .into_iter()
+            .find(|speed| speed.move_interval() == interval)
+    }
+}
+
+/// Square arena sizes offered on the settings screen, in cells per side.
+pub const ARENA_SIZES: [u32; 4] = [10, 15, ARENA_WIDTH, 25];
+
+/// Persistent gameplay preferences. They apply from the next run on; Daily
+/// runs ignore them so every player gets the same challenge.
+#[derive(Resource, SettingsGroup, Reflect)]
+#[reflect(Resource, SettingsGroup, Default)]
+pub struct GameplaySettings {
+    pub speed: Speed,
+    /// Cells per side; one of [`ARENA_SIZES`].
+    pub arena_size: u32,
+    /// Whether the arena edges are deadly instead of wrapping around.
+    pub walls: bool,
+}
+
+impl Default for GameplaySettings {
+    fn default() -> Self {
+        GameplaySettings {
+            speed: Speed::Normal,
+            arena_size: ARENA_WIDTH,
+            walls: false,
+        }
+    }
+}
+
+/// Persistent strength of the visual effects, each `0.0..=1.0` of the
+/// designed look.
+#[derive(Resource, SettingsGroup, Reflect)]
+#[reflect(Resource, SettingsGroup, Default)]
+pub struct EffectsSettings {
+    pub bloom: f32,
+    pub shake: f32,
+    pub particles: f32,
+}
+
+impl Default for EffectsSettings {
+    fn default() -> Self {
+        EffectsSettings {
+            bloom: 1.0,
+            shake: 1.0,
+            particles: 1.0,
+        }
+    }
+}
+
+/// Persistent movement keys. The arrow keys always work as well.
+#[derive(Resource, SettingsGroup, Reflect)]
+#[reflect(Resource, SettingsGroup, Default)]
+pub struct KeyBindings {
+    pub up: KeyCode,
+    pub down: KeyCode,
+    pub left: KeyCode,
+    pub right: KeyCode,
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        KeyBindings {
+            up: KeyCode::KeyW,
+            down: KeyCode::KeyS,
+            left: KeyCode::KeyA,
+            right: KeyCode::KeyD,
+        }
+    }
+}
+
+impl KeyBindings {
+    /// The key bound to `direction`.
+    pub fn key(&self, direction: Direction) -> KeyCode {
+        match direction {
+            Direction::Up => self.up,
+            Direction::Down => self.down,
+            Direction::Left => self.left,
+            Direction::Right => self.right,
+        }
+    }
+
+    /// Mutable key binding for `direction`.
+    pub fn key_mut(&mut self, direction: Direction) -> &mut KeyCode {
+        match direction {
+            Direction::Up => &mut self.up,
+            Direction::Down => &mut self.down,
+            Direction::Left => &mut self.left,
+            Direction::Right => &mut self.right,
+        }
+    }
+}
+
+/// The playfield of the current run. Set from [`GameplaySettings`] when a
+/// run starts, so changing the settings never reshapes a run in progress.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Arena {
+    pub width: u32,
+    pub height: u32,
+    /// Leaving the arena ends the run instead of wrapping around.
+    pub walls: bool,
+    /// Time between two snake moves.
+    pub move_interval: Duration,
+}
+
+impl Default for Arena {
+    fn default() -> Self {
+        Arena {
+            width: ARENA_WIDTH,
+            height: ARENA_HEIGHT,
+            walls: false,
+            move_interval: MOVE_INTERVAL,
+        }
+    }
+}
+
+impl Arena {
+    /// The arena a run in `mode` is played on.
+    pub fn for_run(settings: &GameplaySettings, mode: GameMode) -> Self {
+        if mode == GameMode::Daily {
+            return Arena::default();
+        }
+        Arena {
+            width: settings.arena_size,
+            height: settings.arena_size,
+            walls: settings.walls,
+            move_interval: settings.speed.move_interval(),
+        }
+    }
+
+    /// World-space center of the cell at `position`.
+    pub fn to_world(&self, position: Position) -> Vec2 {
+        Vec2::new(
+            (position.x as f32 - self.width as f32 / 2.0 + 0.5) * CELL_SIZE,
+            (position.y as f32 - self.height as f32 / 2.0 + 0.5) * CELL_SIZE,
+        )
+    }
+
+    /// Size of the arena in world units.
+    pub fn world_size(&self) -> Vec2 {
+        Vec2::new(self.width as f32, self.height as f32) * CELL_SIZE
+    }
+
+    /// The cell at world-space `point`, or `None` outside the arena.
+    pub fn cell_at(&self, point: Vec2) -> Option<Position> {
+        let half = Vec2::new(self.width as f32, self.height as f32) / 2.0;
+        let cell = (point / CELL_SIZE + half).floor();
+        let position = Position {
+            x: cell.x as i32,
+            y: cell.y as i32,
+        };
+        self.board().contains(position).then_some(position)
+    }
+
+    /// How much larger than the default arena this one is. The view, its
+    /// padding and the HUD bar scale by this, so smaller arenas get bigger
+    /// cells on screen.
+    fn view_scale(&self) -> f32 {
+        self.width.max(self.height) as f32 / ARENA_WIDTH as f32
+    }
+
+    /// Side of the square the camera fits into the window: the arena, its
+    /// padding, and the HUD bar above it.
+    pub fn view_side(&self) -> f32 {
+        DEFAULT_VIEW_SIDE * self.view_scale()
+    }
+
+    /// Center of that square, where the camera rests: half the HUD bar above
+    /// the arena's center.
+    pub fn view_center(&self) -> Vec2 {
+        Vec2::new(0.0, HUD_HEIGHT / 2.0 * self.view_scale())
+    }
+
+    /// The arena as the game rules see it.
+    pub fn board(&self) -> Board {
+        Board {
+            width: self.width,
+            height: self.height,
+            walls: self.walls,
+        }
+    }
+}
+
+/// A page of the settings screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SettingsPage {
+    #[default]
+    Gameplay,
+    Display,
+    Accessibility,
+    Audio,
+    Controls,
+}
+
+impl SettingsPage {
+    /// Every page, in tab order.
+    pub const ALL: [SettingsPage; 5] = [
+        SettingsPage::Gameplay,
+        SettingsPage::Display,
+        SettingsPage::Accessibility,
+        SettingsPage::Audio,
+        SettingsPage::Controls,
+    ];
+
+    /// Catalogue key of the tab label.
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            SettingsPage::Gameplay => "page.gameplay",
+            SettingsPage::Display => "page.display",
+            SettingsPage::Accessibility => "page.accessibility",
+            SettingsPage::Audio => "page.audio",
+            SettingsPage::Controls => "page.controls",
+        }
+    }
+}
+
+/// Which screen the start menu is currently showing.
+///
+/// Changing it makes `show_menu_screen` rebuild the menu overlay.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MenuScreen {
+    #[default]
+    Main,
+    Achievements,
+    Settings(SettingsPage),
+}
+
+/// How far the snake is through its current move: 0.0 right after a move,
+/// 1.0 once the next one is due. Published by `position_translation`, which
+/// keeps the clock its interpolation runs on.
+#[derive(Resource, Debug, Default, Clone, Copy)]
+pub struct MoveProgress(pub f32);
+
 /// Resource for camera shake effect.
 #[derive(Resource)]
 pub struct CameraShake {
@@ -95,3 +692,25 @@ impl Default for CameraShake {
         }
     }
 }
+
+/// Progress of the death sequence that plays between a fatal move and the
+/// game-over screen: slow motion, a flashing head, then the snake bursting
+/// apart from head to tail.
+#[derive(Resource, Debug, Default)]
+pub struct DeathSequence {
+    /// Real time since the snake died; `None` when no sequence is playing.
+    pub elapsed: Option<Duration>,
+    /// How many snake parts have burst so far, counted from the head.
+    pub burst: usize,
+    /// The part that doesn't burst: the one under the death highlight (the
+    /// bitten segment, or the head after a wall crash), by its index in
+    /// `GameState::snake_segments`.
+    pub kept: Option<usize>,
+}
+
+impl DeathSequence {
+    /// Whether the sequence is still holding back the game-over screen.
+    pub fn is_playing(&self) -> bool {
+        self.elapsed.is_some()
+    }
+}
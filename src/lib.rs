@@ -0,0 +1,5 @@
+//! The parts of the snake game that don't depend on a front-end, shared by
+//! the Bevy game (`src/main.rs`) and the terminal build
+//! (`src/bin/snake-tui.rs`).
+
+pub mod rules;
@@ -0,0 +1,303 @@
+//! Locale plugin - owns the active [`Locale`]: the message catalogue every
+//! menu, HUD and toast looks its text up in instead of hard-coding English.
+//!
+//! Catalogues are RON files in `locales/`, embedded at compile time. English
+//! is the source catalogue; any key another catalogue leaves out falls back
+//! to the English text. The built-in UI font only has ASCII glyphs, so only
+//! languages that read fine without accents ship for now.
+//!
+//! The language is picked on the settings screen, so a change re-renders
+//! the menu's `bsn!` scenes (`show_menu_screen` watches the [`Locale`]) and
+//! refreshes the HUD; everything else is spawned after the switch.
+
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use crate::game::{GameSet, LanguageSettings};
+
+/// The built-in catalogues, in picker order. The first is the source
+/// catalogue every other one falls back to.
+const BUILT_IN_LOCALES: [&str; 2] = [
+    include_str!("../../locales/en.ron"),
+    include_str!("../../locales/nl.ron"),
+];
+
+/// How a language picks between the forms of a plural message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+pub enum PluralRule {
+    /// `one` for exactly 1, `other` for everything else (English, Dutch,
+    /// German, ...).
+    #[default]
+    OneOther,
+    /// `one` for 1, `few` for numbers ending in 2-4 (but not 12-14), `many`
+    /// for the rest (Polish, Czech-style languages).
+    OneFewMany,
+}
+
+/// The forms of one plural message. Only `other` is required; a missing
+/// form falls back to it.
+#[derive(Debug, Clone, Deserialize)]
+pub struct PluralForms {
+    #[serde(default)]
+    pub one: Option<String>,
+    #[serde(default)]
+    pub few: Option<String>,
+    #[serde(default)]
+    pub many: Option<String>,
+    pub other: String,
+}
+
+impl PluralForms {
+    /// The form `rule` picks for `n`.
+    fn select(&self, rule: PluralRule, n: usize) -> &str {
+        let few = (2..=4).contains(&(n % 10)) && !(12..=14).contains(&(n % 100));
+        let form = match rule {
+            _ if n == 1 => self.one.as_deref(),
+            PluralRule::OneOther => None,
+            PluralRule::OneFewMany if few => self.few.as_deref(),
+            PluralRule::OneFewMany => self.many.as_deref(),
+        };
+        form.unwrap_or(&self.other)
+    }
+}
+
+/// A message catalogue. Messages may contain `{name}` placeholders, filled
+/// in by [`Locale::format`]; plural messages get the count as `{n}`.
+#[derive(Resource, Debug, Clone, Deserialize)]
+pub struct Locale {
+    /// Stored in [`LanguageSettings`].
+    pub code: String,
+    /// The language's own name for itself, shown in the language picker.
+    pub name: String,
+    #[serde(default)]
+    pub plural_rule: PluralRule,
+    #[serde(default)]
+    messages: HashMap<String, String>,
+    #[serde(default)]
+    plurals: HashMap<String, PluralForms>,
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Locale::parse(BUILT_IN_LOCALES[0]).expect("built-in catalogue must parse")
+    }
+}
+
+impl Locale {
+    /// Parses a catalogue from RON source.
+    pub fn parse(source: &str) -> Result<Locale, ron::error::SpannedError> {
+        ron::from_str(source)
+    }
+
+    /// The message for `key`, if the catalogue has one.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.messages.get(key).map(String::as_str)
+    }
+
+    /// The message for `key`. A missing key shows up as the key itself, so
+    /// a gap in a catalogue is visible instead of blank.
+    pub fn text(&self, key: &str) -> String {
+        self.get(key).unwrap_or(key).to_string()
+    }
+
+    /// The message for `key` with each `{name}` placeholder replaced by the
+    /// matching argument.
+    pub fn format(&self, key: &str, args: &[(&str, &dyn Display)]) -> String {
+        fill(self.get(key).unwrap_or(key), args)
+    }
+
+    /// The plural message for `key` in the form this language uses for `n`,
+    /// with `{n}` replaced by the count.
+    pub fn plural(&self, key: &str, n: usize) -> String {
+        match self.plurals.get(key) {
+            Some(forms) => fill(forms.select(self.plural_rule, n), &[("n", &n)]),
+            None => key.to_string(),
+        }
+    }
+
+    /// Adds every message and plural from `fallback` this catalogue lacks.
+    fn fill_gaps(&mut self, fallback: &Locale) {
+        for (key, message) in &fallback.messages {
+            self.messages
+                .entry(key.clone())
+                .or_insert_with(|| message.clone());
+        }
+        for (key, forms) in &fallback.plurals {
+            self.plurals
+                .entry(key.clone())
+                .or_insert_with(|| forms.clone());
+        }
+    }
+}
+
+/// Replaces each `{name}` in `template` with its argument.
+fn fill(template: &str, args: &[(&str, &dyn Display)]) -> String {
+    let mut text = template.to_string();
+    for (name, value) in args {
+        text = text.replace(&format!("{{{name}}}"), &value.to_string());
+    }
+    text
+}
+
+/// Every language the player can pick, in picker order.
+#[derive(Resource)]
+pub struct LocaleLibrary {
+    pub locales: Vec<Locale>,
+}
+
+impl LocaleLibrary {
+    /// Parses the built-in catalogues, filling each one's gaps from the
+    /// source catalogue.
+    fn load() -> Self {
+        let mut locales: Vec<Locale> = BUILT_IN_LOCALES
+            .iter()
+            .map(|source| Locale::parse(source).expect("built-in catalogue must parse"))
+            .collect();
+        let source = locales[0].clone();
+        for locale in &mut locales[1..] {
+            locale.fill_gaps(&source);
+        }
+        LocaleLibrary { locales }
+    }
+
+    /// The catalogue for language `code`, if there is one.
+    pub fn get(&self, code: &str) -> Option<&Locale> {
+        self.locales.iter().find(|locale| locale.code == code)
+    }
+}
+
+/// Plugin for the catalogue library and the active locale.
+pub struct LocalePlugin;
+
+impl Plugin for LocalePlugin {
+    fn build(&self, app: &mut App) {
+        let library = LocaleLibrary::load();
+        // `SettingsPlugin` has already loaded the saved language, so the
+        // very first frame is shown in it.
+        let locale = app
+            .world()
+            .get_resource::<LanguageSettings>()
+            .and_then(|settings| library.get(&settings.language))
+            .cloned()
+            .unwrap_or_default();
+        app.insert_resource(locale)
+            .insert_resource(library)
+            .add_systems(Update, refresh_locale.in_set(GameSet::Ui));
+    }
+}
+
+/// Switches the active [`Locale`] when the language setting changes.
+fn refresh_locale(
+    library: Res<LocaleLibrary>,
+    settings: Res<LanguageSettings>,
+    mut locale: ResMut<Locale>,
+) {
+    // The plugin already picked the first locale from the loaded settings.
+    if locale.is_added() || !settings.is_changed() {
+        return;
+    }
+    *locale = library.get(&settings.language).cloned().unwrap_or_default();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn built_in_catalogues_only_translate_known_keys() {
+        let english = Locale::parse(BUILT_IN_LOCALES[0]).unwrap();
+        for source in &BUILT_IN_LOCALES[1..] {
+            let locale = Locale::parse(source).unwrap();
+            for key in locale.messages.keys() {
+                assert!(
+                    english.messages.contains_key(key) || key.starts_with("achievement."),
+                    "{}: unknown key {key}",
+                    locale.code
+                );
+            }
+            for key in locale.plurals.keys() {
+                assert!(
+                    english.plurals.contains_key(key),
+                    "{}: unknown plural {key}",
+                    locale.code
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn built_in_catalogues_translate_every_key() {
+        let english = Locale::parse(BUILT_IN_LOCALES[0]).unwrap();
+        for source in &BUILT_IN_LOCALES[1..] {
+            // Parsed as shipped, before any gap is filled from English.
+            let locale = Locale::parse(source).unwrap();
+            for key in english.messages.keys() {
+                assert!(
+                    locale.messages.contains_key(key),
+                    "{}: missing {key}",
+                    locale.code
+                );
+            }
+            for key in english.plurals.keys() {
+                assert!(
+                    locale.plurals.contains_key(key),
+                    "{}: missing plural {key}",
+                    locale.code
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn missing_keys_fall_back_to_english() {
+        let english = Locale::default();
+        let mut partial = Locale::parse(
+            r#"(
+                code: "xx",
+                name: "Partial",
+                messages: { "hud.score": "Punten: {score}" },
+            )"#,
+        )
+        .unwrap();
+        assert_eq!(partial.get("end.menu"), None);
+
+        partial.fill_gaps(&english);
+        assert_eq!(partial.text("end.menu"), english.text("end.menu"));
+        assert_eq!(partial.plural("end.best", 2), "Best: 2 apples");
+        // What it does translate is kept.
+        assert_eq!(partial.format("hud.score", &[("score", &7)]), "Punten: 7");
+    }
+
+    #[test]
+    fn plural_forms_follow_the_rule() {
+        let forms = PluralForms {
+            one: Some("{n} apple".into()),
+            few: Some("{n} apples (few)".into()),
+            many: Some("{n} apples (many)".into()),
+            other: "{n} apples".into(),
+        };
+        assert_eq!(forms.select(PluralRule::OneOther, 1), "{n} apple");
+        assert_eq!(forms.select(PluralRule::OneOther, 0), "{n} apples");
+        assert_eq!(forms.select(PluralRule::OneOther, 22), "{n} apples");
+        assert_eq!(forms.select(PluralRule::OneFewMany, 1), "{n} apple");
+        assert_eq!(forms.select(PluralRule::OneFewMany, 3), "{n} apples (few)");
+        assert_eq!(
+            forms.select(PluralRule::OneFewMany, 12),
+            "{n} apples (many)"
+        );
+        assert_eq!(forms.select(PluralRule::OneFewMany, 24), "{n} apples (few)");
+        assert_eq!(forms.select(PluralRule::OneFewMany, 5), "{n} apples (many)");
+    }
+
+    #[test]
+    fn format_fills_placeholders() {
+        let locale = Locale::default();
+        assert_eq!(locale.format("hud.score", &[("score", &7)]), "Score: 7");
+        assert_eq!(locale.plural("end.best", 1), "Best: 1 apple");
+        assert_eq!(locale.plural("end.best", 2), "Best: 2 apples");
+        assert_eq!(locale.text("no.such.key"), "no.such.key");
+    }
+}
@@ -1,50 +1,73 @@
 //! Snake game built with Bevy.
 
-use bevy::settings::SettingsPlugin;
+use bevy::settings::{SaveSettings, SettingsPlugin};
 use bevy::{prelude::*, window::WindowResolution};
 use bevy_vector_shapes::prelude::*;
 
+mod achievements;
+mod audio;
+mod daily;
+mod debug;
+mod export;
 mod food;
 mod game;
+mod locale;
+mod perf;
 mod rendering;
 mod snake;
+mod stats;
+#[cfg(test)]
+mod tests;
+mod theme;
 mod ui;
 
+use achievements::AchievementsPlugin;
+use audio::SoundPlugin;
+use daily::DailyPlugin;
+use debug::DebugPlugin;
+use export::ExportPlugin;
 use food::FoodPlugin;
 use game::{
-    ARENA_HEIGHT, ARENA_WIDTH, BACKGROUND_COLOR, CELL_SIZE, CameraShake, FoodEatenEvent, GameSet,
-    GameState, GrowthEvent, HighScore, InputBuffer, StartRequested, WINDOW_PADDING,
+    AccessibilitySettings, AudioSettings, DEFAULT_VIEW_SIDE, DailyRecords, EffectsSettings,
+    GamePlugin, GameplaySettings, HighScore, KeyBindings, LanguageSettings, SaveRequested,
+    ThemeSettings, TimeAttackRecords, UnlockedAchievements, ZenSettings,
 };
+use locale::LocalePlugin;
+use perf::PerfPlugin;
 use rendering::RenderingPlugin;
 use snake::SnakePlugin;
+use stats::StatsPlugin;
+use theme::ThemePlugin;
 use ui::UiPlugin;
 
 fn main() {
     App::new()
-        // Enforce deterministic cross-plugin execution order every frame:
-        //   Movement → Collision → Effects → Rendering → Ui
-        .configure_sets(
-            Update,
-            (
-                GameSet::Movement,
-                GameSet::Collision,
-                GameSet::Effects,
-                GameSet::Rendering,
-                GameSet::Ui,
-            )
-                .chain(),
-        )
-        // Persistent settings (high score). The type must be registered
-        // before `SettingsPlugin` is added — the plugin scans the type
-        // registry and loads the settings file the moment it is built.
+        // Persistent settings (high scores, achievements, preferences). The
+        // types must be registered before `SettingsPlugin` is added — the
+        // plugin scans the type registry and loads the settings file the
+        // moment it is built.
         .register_type::<HighScore>()
+        .register_type::<TimeAttackRecords>()
+        .register_type::<ZenSettings>()
+        .register_type::<DailyRecords>()
+        .register_type::<UnlockedAchievements>()
+        .register_type::<AudioSettings>()
+        .register_type::<ThemeSettings>()
+        .register_type::<LanguageSettings>()
+        .register_type::<AccessibilitySettings>()
+        .register_type::<GameplaySettings>()
+        .register_type::<EffectsSettings>()
+        .register_type::<KeyBindings>()
         .add_plugins(SettingsPlugin::new("io.github.eeabed.snake_bevy"))
         .add_plugins((
             DefaultPlugins.set(WindowPlugin {
                 primary_window: Some(Window {
+                    // Only the initial size: the camera fits the arena to
+                    // the window however it is resized (F11: fullscreen).
+                    // The default arena, its padding and the HUD bar.
                     resolution: WindowResolution::new(
-                        (ARENA_WIDTH as f32 * CELL_SIZE + WINDOW_PADDING) as u32,
-                        (ARENA_HEIGHT as f32 * CELL_SIZE + WINDOW_PADDING) as u32,
+                        DEFAULT_VIEW_SIDE as u32,
+                        DEFAULT_VIEW_SIDE as u32,
                     ),
                     title: "Snake Game".to_string(),
                     // On the web, render into the page's existing canvas
@@ -57,15 +80,31 @@ fn main() {
             Shape2dPlugin::default(),
         ))
         // Game plugins
-        .add_plugins((SnakePlugin, FoodPlugin, RenderingPlugin, UiPlugin))
-        // Resources
-        .insert_resource(ClearColor(BACKGROUND_COLOR))
-        .init_resource::<GameState>()
-        .init_resource::<InputBuffer>()
-        .init_resource::<CameraShake>()
-        // Events
-        .add_message::<GrowthEvent>()
-        .add_message::<FoodEatenEvent>()
-        .add_message::<StartRequested>()
+        .add_plugins((
+            GamePlugin,
+            ThemePlugin,
+            LocalePlugin,
+            SnakePlugin,
+            FoodPlugin,
+            RenderingPlugin,
+            UiPlugin,
+            StatsPlugin,
+            AchievementsPlugin,
+            DailyPlugin,
+            SoundPlugin,
+            ExportPlugin,
+            DebugPlugin,
+            PerfPlugin,
+        ))
+        .add_systems(Last, save_settings)
         .run();
 }
+
+/// Saves the settings file when anything asked this frame, once however
+/// many asked. The save is asynchronous (file I/O happens on another thread)
+/// and crash-safe: `bevy_settings` writes to a temp file and renames.
+fn save_settings(mut commands: Commands, mut requests: MessageReader<SaveRequested>) {
+    if requests.read().count() > 0 {
+        commands.queue(SaveSettings::IfChanged);
+    }
+}
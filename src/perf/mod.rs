@@ -0,0 +1,499 @@
+//! Performance HUD (F2): frame time, FPS, entity count and the time each
+//! [`GameSet`] takes, for finding out what makes frames drop (a long snake,
+//! a lot of particles, a big checkerboard), on the native and WebGPU builds
+//! alike.
+//!
+//! While the HUD is showing, every frame is logged as a sample, keeping the
+//! last [`MAX_SAMPLES`]. F4 saves the log to `snake-perf-<timestamp>.csv`
+//! (the web build downloads it) and starts a fresh one; on the native build
+//! whatever is left is also saved when the game exits.
+//!
+//! A set's time is the wall-clock time between probe systems on either side
+//! of it. Systems outside the game sets can run in parallel with a set and
+//! end up counted in its time, so read the numbers as upper bounds.
+
+use bevy::diagnostic::{
+    Diagnostic, DiagnosticPath, Diagnostics, DiagnosticsStore, EntityCountDiagnosticsPlugin,
+    FrameTimeDiagnosticsPlugin, RegisterDiagnostic,
+};
+use bevy::platform::time::Instant;
+use bevy::prelude::*;
+
+use std::collections::VecDeque;
+
+use crate::daily;
+use crate::game::{FrameTimeBar, GameSet, PerfPanel, PerfText, SetTimeBar};
+
+/// The timed sets, in execution order, with the diagnostic each one's time
+/// is recorded under.
+const TIMED_SETS: [(GameSet, DiagnosticPath); 5] = [
+    (
+        GameSet::Movement,
+        DiagnosticPath::const_new("game_set/movement"),
+    ),
+    (
+        GameSet::Collision,
+        DiagnosticPath::const_new("game_set/collision"),
+    ),
+    (
+        GameSet::Effects,
+        DiagnosticPath::const_new("game_set/effects"),
+    ),
+    (
+        GameSet::Rendering,
+        DiagnosticPath::const_new("game_set/rendering"),
+    ),
+    (GameSet::Ui, DiagnosticPath::const_new("game_set/ui")),
+];
+
+/// Samples kept in the log: ten minutes at 60 FPS. Older ones are dropped.
+const MAX_SAMPLES: usize = 36_000;
+
+/// Frames shown in the frame-time graph, one bar each, newest on the right.
+const GRAPH_BARS: usize = 120;
+const GRAPH_HEIGHT_PX: f32 = 48.0;
+/// Frame time, in ms, that fills the graph's height (30 FPS).
+const GRAPH_CEILING_MS: f64 = 1000.0 / 30.0;
+/// Frame time, in ms, of 60 FPS; slower frames are drawn in the warning
+/// color, frames slower than the graph's ceiling in the alarm color.
+const FRAME_BUDGET_MS: f64 = 1000.0 / 60.0;
+const SET_BAR_WIDTH_PX: f32 = 120.0;
+/// Set time, in ms, that fills a set's bar.
+const SET_BAR_CEILING_MS: f64 = 4.0;
+
+const TEXT_COLOR: Color = Color::srgb(0.9, 0.9, 0.9);
+const GOOD_COLOR: Color = Color::srgb(0.3, 0.85, 0.4);
+const WARNING_COLOR: Color = Color::srgb(0.95, 0.8, 0.2);
+const ALARM_COLOR: Color = Color::srgb(0.95, 0.3, 0.3);
+
+/// Plugin for the F2 performance HUD.
+pub struct PerfPlugin;
+
+impl Plugin for PerfPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins((
+            FrameTimeDiagnosticsPlugin::default(),
+            EntityCountDiagnosticsPlugin::default(),
+        ));
+        for (_, path) in &TIMED_SETS {
+            app.register_diagnostic(Diagnostic::new(path.clone()).with_suffix("ms"));
+        }
+        app.init_resource::<PerfHud>()
+            .init_resource::<SetProbes>()
+            .add_systems(Startup, spawn_perf_panel)
+            .add_systems(
+                Update,
+                (
+                    probe::<0>.before(GameSet::Movement),
+                    probe::<1>
+                        .after(GameSet::Movement)
+                        .before(GameSet::Collision),
+                    probe::<2>
+                        .after(GameSet::Collision)
+                        .before(GameSet::Effects),
+                    probe::<3>
+                        .after(GameSet::Effects)
+                        .before(GameSet::Rendering),
+                    probe::<4>.after(GameSet::Rendering).before(GameSet::Ui),
+                    probe::<5>.after(GameSet::Ui),
+                    (toggle_perf_hud, save_samples_on_key).in_set(GameSet::Ui),
+                ),
+            )
+            // After `Update`, so this frame's set times are in.
+            .add_systems(
+                Last,
+                (
+                    record_set_times,
+                    record_sample,
+                    update_perf_panel,
+                    save_samples_on_exit,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// One frame's numbers, as logged while the HUD shows.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Sample {
+    /// Real time since startup.
+    seconds: f64,
+    frame_ms: f64,
+    fps: f64,
+    entities: f64,
+    /// Time of each of [`TIMED_SETS`], in the same order.
+    set_ms: [f64; TIMED_SETS.len()],
+}
+
+/// Whether the HUD is showing, and the last [`MAX_SAMPLES`] samples logged
+/// while it was.
+#[derive(Resource, Default)]
+struct PerfHud {
+    visible: bool,
+    samples: VecDeque<Sample>,
+}
+
+impl PerfHud {
+    /// Logs a sample, dropping the oldest once the log is full.
+    fn push(&mut self, sample: Sample) {
+        if self.samples.len() == MAX_SAMPLES {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(sample);
+    }
+
+    /// The logged samples as CSV, one frame per row.
+    fn to_csv(&self) -> String {
+        let mut csv = String::from("seconds,frame_ms,fps,entities");
+        for (set, _) in &TIMED_SETS {
+            csv += &format!(",{}_ms", format!("{set:?}").to_lowercase());
+        }
+        csv.push('\n');
+        for sample in &self.samples {
+            csv += &format!(
+                "{:.4},{:.3},{:.1},{}",
+                sample.seconds, sample.frame_ms, sample.fps, sample.entities
+            );
+            for ms in sample.set_ms {
+                csv += &format!(",{ms:.4}");
+            }
+            csv.push('\n');
+        }
+        csv
+    }
+}
+
+/// When each probe last ran this frame: probe `i` runs right before the
+/// `i`-th timed set, and the last one after the last set.
+#[derive(Resource, Default)]
+struct SetProbes {
+    marks: [Option<Instant>; TIMED_SETS.len() + 1],
+}
+
+fn probe<const I: usize>(mut probes: ResMut<SetProbes>) {
+    probes.marks[I] = Some(Instant::now());
+}
+
+/// Records each set's time from the probe marks.
+fn record_set_times(mut probes: ResMut<SetProbes>, mut diagnostics: Diagnostics) {
+    for (i, (_, path)) in TIMED_SETS.iter().enumerate() {
+        if let (Some(start), Some(end)) = (probes.marks[i], probes.marks[i + 1]) {
+            diagnostics.add_measurement(path, || {
+                end.saturating_duration_since(start).as_secs_f64() * 1000.0
+            });
+        }
+    }
+    probes.marks = default();
+}
+
+/// Logs this frame while the HUD shows.
+fn record_sample(mut hud: ResMut<PerfHud>, store: Res<DiagnosticsStore>, time: Res<Time<Real>>) {
+    if !hud.visible {
+        return;
+    }
+    let latest = |path: &DiagnosticPath| store.get(path).and_then(Diagnostic::value);
+    let sample = Sample {
+        seconds: time.elapsed_secs_f64(),
+        frame_ms: latest(&FrameTimeDiagnosticsPlugin::FRAME_TIME).unwrap_or(0.0),
+        fps: latest(&FrameTimeDiagnosticsPlugin::FPS).unwrap_or(0.0),
+        entities: latest(&EntityCountDiagnosticsPlugin::ENTITY_COUNT).unwrap_or(0.0),
+        set_ms: TIMED_SETS
+            .each_ref()
+            .map(|(_, path)| latest(path).unwrap_or(0.0)),
+    };
+    hud.push(sample);
+}
+
+/// The HUD panel in the bottom-right corner, hidden until F2 is pressed:
+/// the numbers, the frame-time graph, then a bar per set.
+fn perf_panel() -> impl Scene {
+    let frame_bars: Vec<_> = (0..GRAPH_BARS)
+        .map(|index| {
+            bsn! {
+                FrameTimeBar { index: { index } }
+                Node {
+                    width: { Val::Px(1.0) },
+                    height: { Val::Px(0.0) },
+                }
+                BackgroundColor(GOOD_COLOR)
+            }
+        })
+        .collect();
+    let set_rows: Vec<_> = TIMED_SETS
+        .iter()
+        .enumerate()
+        .map(|(index, (set, _))| {
+            let name = format!("{set:?}");
+            bsn! {
+                Node {
+                    align_items: AlignItems::Center,
+                    column_gap: { Val::Px(6.0) },
+                }
+                Children [
+                    (
+                        Text(name)
+                        TextFont {
+                            font_size: { FontSize::Px(11.0) },
+                        }
+                        TextColor(TEXT_COLOR)
+                        Node { width: { Val::Px(64.0) } }
+                    ),
+                    (
+                        SetTimeBar { index: { index } }
+                        Node {
+                            width: { Val::Px(0.0) },
+                            height: { Val::Px(6.0) },
+                        }
+                        BackgroundColor(GOOD_COLOR)
+                    ),
+                ]
+            }
+        })
+        .collect();
+    bsn! {
+        PerfPanel
+        Node {
+            position_type: PositionType::Absolute,
+            right: { Val::Px(8.0) },
+            bottom: { Val::Px(8.0) },
+            padding: { UiRect::all(Val::Px(6.0)) },
+            row_gap: { Val::Px(4.0) },
+            flex_direction: FlexDirection::Column,
+        }
+        BackgroundColor({ Color::srgba(0.0, 0.0, 0.0, 0.75) })
+        GlobalZIndex(10)
+        Visibility::Hidden
+        Children [
+            (
+                PerfText
+                Text("")
+                TextFont {
+                    font_size: { FontSize::Px(13.0) },
+                }
+                TextColor(TEXT_COLOR)
+            ),
+            (
+                Node {
+                    height: { Val::Px(GRAPH_HEIGHT_PX) },
+                    align_items: AlignItems::FlexEnd,
+                }
+                Children [{ frame_bars }]
+            ),
+            { set_rows },
+        ]
+    }
+}
+
+fn spawn_perf_panel(mut commands: Commands) {
+    commands.spawn_scene(perf_panel());
+}
+
+/// Shows or hides the HUD on F2.
+fn toggle_perf_hud(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut hud: ResMut<PerfHud>,
+    mut panels: Query<&mut Visibility, With<PerfPanel>>,
+) {
+    if !keyboard_input.just_pressed(KeyCode::F2) {
+        return;
+    }
+    hud.visible = !hud.visible;
+    for mut visibility in &mut panels {
+        *visibility = if hud.visible {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+}
+
+/// The graph and bar color for a frame or set taking `fraction` of its
+/// budget.
+fn load_color(fraction: f64) -> Color {
+    if fraction <= 1.0 {
+        GOOD_COLOR
+    } else if fraction <= GRAPH_CEILING_MS / FRAME_BUDGET_MS {
+        WARNING_COLOR
+    } else {
+        ALARM_COLOR
+    }
+}
+
+/// Refreshes the numbers, graph and bars while the HUD shows. Only what
+/// changed is written, and bar sizes are rounded to whole pixels, so the
+/// HUD doesn't add layout and text work of its own to the frames it times.
+fn update_perf_panel(
+    hud: Res<PerfHud>,
+    store: Res<DiagnosticsStore>,
+    mut text: Query<&mut Text, With<PerfText>>,
+    mut frame_bars: Query<(&FrameTimeBar, &mut Node, &mut BackgroundColor), Without<SetTimeBar>>,
+    mut set_bars: Query<(&SetTimeBar, &mut Node, &mut BackgroundColor), Without<FrameTimeBar>>,
+) {
+    if !hud.visible {
+        return;
+    }
+    let smoothed = |path: &DiagnosticPath| store.get(path).and_then(Diagnostic::smoothed);
+    let set_ms = TIMED_SETS
+        .each_ref()
+        .map(|(_, path)| smoothed(path).unwrap_or(0.0));
+
+    if let Ok(mut text) = text.single_mut() {
+        let mut report = format!(
+            "FPS {:.0}  frame {:.1} ms  entities {:.0}",
+            smoothed(&FrameTimeDiagnosticsPlugin::FPS).unwrap_or(0.0),
+            smoothed(&FrameTimeDiagnosticsPlugin::FRAME_TIME).unwrap_or(0.0),
+            store
+                .get(&EntityCountDiagnosticsPlugin::ENTITY_COUNT)
+                .and_then(Diagnostic::value)
+                .unwrap_or(0.0),
+        );
+        for ((set, _), ms) in TIMED_SETS.iter().zip(set_ms) {
+            report += &format!("\n{set:?} {ms:.2} ms");
+        }
+        if text.0 != report {
+            *text = Text::from(report);
+        }
+    }
+
+    let history: Vec<f64> = store
+        .get(&FrameTimeDiagnosticsPlugin::FRAME_TIME)
+        .map(|diagnostic| diagnostic.values().copied().collect())
+        .unwrap_or_default();
+    // Right-align the history so the newest frame is the last bar.
+    let offset = GRAPH_BARS as isize - history.len() as isize;
+    for (bar, mut node, mut color) in &mut frame_bars {
+        let ms = usize::try_from(bar.index as isize - offset)
+            .ok()
+            .and_then(|i| history.get(i))
+            .copied()
+            .unwrap_or(0.0);
+        let fraction = (ms / GRAPH_CEILING_MS).min(1.0) as f32;
+        let height = Val::Px((GRAPH_HEIGHT_PX * fraction).round());
+        if node.height != height {
+            node.height = height;
+        }
+        color.set_if_neq(BackgroundColor(load_color(ms / FRAME_BUDGET_MS)));
+    }
+
+    for (bar, mut node, mut color) in &mut set_bars {
+        let ms = set_ms[bar.index];
+        let fraction = (ms / SET_BAR_CEILING_MS).min(1.0) as f32;
+        let width = Val::Px((SET_BAR_WIDTH_PX * fraction).round());
+        if node.width != width {
+            node.width = width;
+        }
+        color.set_if_neq(BackgroundColor(load_color(ms / (SET_BAR_CEILING_MS / 2.0))));
+    }
+}
+
+/// Saves the logged samples on F4 and starts a fresh log.
+fn save_samples_on_key(keyboard_input: Res<ButtonInput<KeyCode>>, mut hud: ResMut<PerfHud>) {
+    if keyboard_input.just_pressed(KeyCode::F4) && !hud.samples.is_empty() {
+        save_samples(&hud);
+        hud.samples.clear();
+    }
+}
+
+/// Saves the logged samples when the game exits.
+fn save_samples_on_exit(mut exits: MessageReader<AppExit>, hud: Res<PerfHud>) {
+    if exits.read().count() == 0 || hud.samples.is_empty() {
+        return;
+    }
+    save_samples(&hud);
+}
+
+fn save_samples(hud: &PerfHud) {
+    let file_name = format!("snake-perf-{}.csv", daily::unix_seconds());
+    match write_csv(&file_name, &hud.to_csv()) {
+        Ok(()) => info!("Saved {} frame samples to {file_name}", hud.samples.len()),
+        Err(error) => warn!("Couldn't save the frame samples to {file_name}: {error}"),
+    }
+}
+
+/// Writes the CSV to `file_name` in the working directory.
+#[cfg(not(target_arch = "wasm32"))]
+fn write_csv(file_name: &str, csv: &str) -> Result<(), String> {
+    std::fs::write(file_name, csv).map_err(|error| error.to_string())
+}
+
+/// The web build has no file system to write to, so the browser downloads
+/// the CSV as `file_name` instead, through a throwaway link to a `Blob`.
+#[cfg(target_arch = "wasm32")]
+fn write_csv(file_name: &str, csv: &str) -> Result<(), String> {
+    use wasm_bindgen::JsCast;
+
+    let js_error = |error: wasm_bindgen::JsValue| format!("{error:?}");
+    let options = web_sys::BlobPropertyBag::new();
+    options.set_type("text/csv");
+    let blob = web_sys::Blob::new_with_str_sequence_and_options(
+        &js_sys::Array::of1(&csv.into()),
+        &options,
+    )
+    .map_err(js_error)?;
+    let url = web_sys::Url::create_object_url_with_blob(&blob).map_err(js_error)?;
+    let document = web_sys::window()
+        .and_then(|window| window.document())
+        .ok_or("no document to download from")?;
+    let link: web_sys::HtmlAnchorElement = document
+        .create_element("a")
+        .map_err(js_error)?
+        .dyn_into()
+        .map_err(|_| "not an anchor element")?;
+    link.set_href(&url);
+    link.set_download(file_name);
+    link.click();
+    web_sys::Url::revoke_object_url(&url).map_err(js_error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csv_has_a_header_and_a_row_per_sample() {
+        let sample = Sample {
+            seconds: 1.5,
+            frame_ms: 16.667,
+            fps: 60.0,
+            entities: 412.0,
+            set_ms: [0.01, 0.02, 0.03, 0.5, 0.25],
+        };
+        let hud = PerfHud {
+            visible: true,
+            samples: VecDeque::from([sample; 2]),
+        };
+        let csv = hud.to_csv();
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert_eq!(
+            lines[0],
+            "seconds,frame_ms,fps,entities,movement_ms,collision_ms,effects_ms,rendering_ms,ui_ms"
+        );
+        assert_eq!(lines.len(), 3);
+        assert_eq!(
+            lines[1],
+            "1.5000,16.667,60.0,412,0.0100,0.0200,0.0300,0.5000,0.2500"
+        );
+    }
+
+    #[test]
+    fn the_log_keeps_only_the_newest_samples() {
+        let mut hud = PerfHud::default();
+        for frame in 0..MAX_SAMPLES + 10 {
+            hud.push(Sample {
+                seconds: frame as f64,
+                frame_ms: 16.667,
+                fps: 60.0,
+                entities: 412.0,
+                set_ms: [0.0; TIMED_SETS.len()],
+            });
+        }
+
+        assert_eq!(hud.samples.len(), MAX_SAMPLES);
+        assert_eq!(hud.samples.front().map(|sample| sample.seconds), Some(10.0));
+        assert_eq!(
+            hud.samples.back().map(|sample| sample.seconds),
+            Some((MAX_SAMPLES + 9) as f64)
+        );
+    }
+}
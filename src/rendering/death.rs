@@ -0,0 +1,180 @@
+//! The death sequence: what plays between the fatal move and the game-over
+//! screen.
+//!
+//! 1. Slow motion: the final slide, the camera shake and anything still in
+//!    flight play at a fraction of their speed.
+//! 2. The head flashes.
+//! 3. The snake bursts into [`Particle`]s one part at a time, head to tail.
+//!    Burst parts are hidden and marked [`Burst`], not despawned. The part
+//!    the snake died on stays, under its red glow, on the frozen board.
+//!
+//! Only then does `spawn_game_over_screen_system` fade the overlay in.
+//! SPACE or ESC skips straight to the end. With reduced motion on, the
+//! sequence doesn't play at all and the overlay appears right away.
+//!
+//! The stages are timed in real time, so slowing the virtual clock doesn't
+//! stretch the sequence itself.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_vector_shapes::prelude::*;
+use rand::prelude::*;
+
+use crate::game::{
+    AccessibilitySettings, Burst, DeathCause, DeathKind, DeathSequence, EffectsSettings, GamePhase,
+    GameState, Particle, SnakeHead, Z_SNAKE_HEAD,
+};
+use crate::theme::Theme;
+
+/// How long the game runs in slow motion after the fatal move.
+const SLOW_MOTION: Duration = Duration::from_millis(600);
+/// Speed of the virtual clock during slow motion.
+const SLOW_MOTION_SPEED: f32 = 0.25;
+/// How long the head flashes once slow motion ends.
+const FLASH: Duration = Duration::from_millis(450);
+/// Length of one flash on / off half-cycle, in seconds.
+const FLASH_PERIOD: f32 = 0.075;
+/// Time between two parts bursting.
+const BURST_INTERVAL: Duration = Duration::from_millis(45);
+/// Upper bound for bursting the whole snake, so long snakes speed up
+/// instead of keeping the player waiting.
+const MAX_BURST_TIME: Duration = Duration::from_millis(1200);
+/// Pause after the tail bursts, before the overlay fades in.
+const OUTRO: Duration = Duration::from_millis(350);
+/// Particles per snake part at full strength.
+const BURST_PARTICLES: f32 = 10.0;
+
+/// Starts the sequence the frame the snake dies.
+///
+/// Runs before `position_translation`, which keeps sliding the snake into
+/// its final cell while the sequence plays.
+pub(super) fn start_death_sequence(
+    game_state: Res<GameState>,
+    accessibility: Res<AccessibilitySettings>,
+    mut deaths: MessageReader<DeathCause>,
+    mut death: ResMut<DeathSequence>,
+) {
+    // Drain unconditionally so a cause can't outlive its frame.
+    let cause = deaths.read().last().copied();
+    if !game_state.is_changed() || game_state.phase != GamePhase::GameOver {
+        return;
+    }
+    if !accessibility.reduce_motion {
+        *death = DeathSequence {
+            elapsed: Some(Duration::ZERO),
+            burst: 0,
+            kept: cause.map(|cause| match cause.kind {
+                DeathKind::SelfBite { segment } => segment,
+                DeathKind::Wall => 0,
+            }),
+        };
+    }
+}
+
+/// Advances the sequence: drives the slow motion, flashes the head and
+/// bursts the parts that are due, then ends it once the outro has passed
+/// (or right away when the player skips).
+#[allow(clippy::too_many_arguments)]
+pub(super) fn play_death_sequence(
+    mut commands: Commands,
+    real_time: Res<Time<Real>>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    game_state: Res<GameState>,
+    theme: Res<Theme>,
+    effects: Res<EffectsSettings>,
+    mut death: ResMut<DeathSequence>,
+    mut parts: Query<(&Transform, &mut ShapeFill, Has<SnakeHead>)>,
+) {
+    let Some(elapsed) = death.elapsed else {
+        // Restore normal speed however the sequence ended.
+        if virtual_time.relative_speed() != 1.0 {
+            virtual_time.set_relative_speed(1.0);
+        }
+        return;
+    };
+    let elapsed = elapsed + real_time.delta();
+    let skipped = keyboard_input.any_just_pressed([KeyCode::Space, KeyCode::Escape]);
+
+    let speed = if elapsed < SLOW_MOTION && !skipped {
+        SLOW_MOTION_SPEED
+    } else {
+        1.0
+    };
+    if virtual_time.relative_speed() != speed {
+        virtual_time.set_relative_speed(speed);
+    }
+
+    let segments = &game_state.snake_segments;
+    let flash_end = SLOW_MOTION + FLASH;
+    // Past the flash the head is put back to its own color, in case it is
+    // the part that is kept.
+    if elapsed >= SLOW_MOTION || skipped {
+        let flashing = (SLOW_MOTION..flash_end).contains(&elapsed) && !skipped;
+        let on =
+            flashing && ((elapsed - SLOW_MOTION).as_secs_f32() / FLASH_PERIOD) as usize % 2 == 0;
+        let color = if on {
+            theme.food_eaten
+        } else {
+            theme.snake_head
+        };
+        if let Some(&head) = segments.first()
+            && let Ok((_, mut fill, _)) = parts.get_mut(head)
+            && fill.color != color
+        {
+            fill.color = color;
+        }
+    }
+
+    // Parts burst evenly over the burst stage, head first.
+    let interval = BURST_INTERVAL.min(MAX_BURST_TIME / segments.len().max(1) as u32);
+    let due = if skipped {
+        segments.len()
+    } else if elapsed < flash_end {
+        0
+    } else {
+        ((elapsed - flash_end).as_secs_f32() / interval.as_secs_f32()) as usize + 1
+    }
+    .min(segments.len());
+    let mut rng = rand::rng();
+    let count = (BURST_PARTICLES * effects.particles).round() as usize;
+    for (index, &entity) in segments.iter().enumerate().take(due).skip(death.burst) {
+        if death.kept == Some(index) {
+            continue;
+        }
+        let Ok((transform, fill, is_head)) = parts.get(entity) else {
+            continue;
+        };
+        let color = if is_head {
+            theme.snake_head
+        } else {
+            fill.color
+        };
+        let center = transform.translation.truncate();
+        for _ in 0..count {
+            let angle = rng.random_range(0.0..std::f32::consts::TAU);
+            let speed = rng.random_range(60.0..190.0);
+            commands.spawn((
+                ShapeBundle::circle(
+                    &ShapeConfig {
+                        color,
+                        alpha_mode: ShapeAlphaMode::Add,
+                        transform: Transform::from_translation(center.extend(Z_SNAKE_HEAD + 0.5)),
+                        ..ShapeConfig::default_2d()
+                    },
+                    rng.random_range(2.0..5.0),
+                ),
+                Particle {
+                    velocity: Vec2::from_angle(angle) * speed,
+                    timer: Timer::from_seconds(rng.random_range(0.4..0.7), TimerMode::Once),
+                },
+            ));
+        }
+        commands.entity(entity).insert((Burst, Visibility::Hidden));
+    }
+    death.burst = due;
+
+    let end = flash_end + interval * segments.len() as u32 + OUTRO;
+    death.elapsed = (elapsed < end && !skipped).then_some(elapsed);
+}
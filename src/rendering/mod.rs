@@ -1,5 +1,9 @@
 //! Rendering plugin - handles position interpolation, rotation, visual effects, and camera.
+//! The death sequence lives in [`death`].
 
+mod death;
+
+use bevy::post_process::bloom::Bloom;
 use bevy::prelude::*;
 use bevy_vector_shapes::prelude::*;
 use rand::prelude::*;
@@ -7,10 +11,25 @@ use rand::prelude::*;
 use std::time::Duration;
 
 use crate::game::{
-    ARENA_HEIGHT, ARENA_WIDTH, CELL_SIZE, CameraShake, Direction, FOOD_EATEN_COLOR, FoodEatenEvent,
-    GamePhase, GameSet, GameState, GrowingSegment, MOVE_INTERVAL, PARTICLE_COLORS, Particle,
-    Position, PreviousPosition, PulseEffect, SCORE_POPUP_COLOR, ScorePopup, SnakeHead, Z_FOOD,
+    AccessibilitySettings, Arena, CELL_SIZE, CameraShake, DeathCause, DeathHighlight,
+    DeathSequence, Direction, EffectsSettings, FadeEffect, FoodEatenEvent, GamePhase, GameSet,
+    GameState, GrowingSegment, MoveProgress, Particle, Position, PreviousPosition, PulseEffect,
+    ScorePopup, SegmentOutline, SnakeEye, SnakeHead, SnakeSegment, SnakeTongue, WrapGhost, Z_FOOD,
+    Z_SNAKE_HEAD,
 };
+use crate::theme::Theme;
+use death::{play_death_sequence, start_death_sequence};
+
+/// Bloom intensity at full strength ([`EffectsSettings::bloom`] = 1.0).
+pub const BLOOM_INTENSITY: f32 = 0.3;
+/// Juice droplets per bite at full strength.
+const PARTICLE_COUNT: f32 = 18.0;
+/// Game-over camera shake, in pixels, at full strength.
+const SHAKE_INTENSITY: f32 = 8.0;
+/// How far from its center a snake part can reach: the head's tongue flicks
+/// almost a full cell forward, body segments stay inside their cell.
+const HEAD_REACH: f32 = CELL_SIZE;
+const SEGMENT_REACH: f32 = CELL_SIZE / 2.0;
 
 /// Plugin for rendering and visual effects.
 pub struct RenderingPlugin;
@@ -20,15 +39,25 @@ impl Plugin for RenderingPlugin {
         app.add_systems(
             Update,
             (
+                start_death_sequence,
+                highlight_death_cause,
                 position_translation,
                 update_head_rotation,
                 pulse_effect_system,
+                fade_effect_system,
                 spawn_food_eaten_effect,
                 particle_update,
                 score_popup_update,
                 camera_shake_system,
                 growing_segment_animation,
                 trigger_camera_shake_on_game_over,
+                apply_bloom_strength,
+                // After the other systems, so the copies match this frame's
+                // final pose.
+                sync_wrap_ghosts,
+                // After the copies are synced: a part it bursts hides its
+                // copies from the next frame on.
+                play_death_sequence,
             )
                 .chain()
                 .in_set(GameSet::Rendering),
@@ -52,18 +81,26 @@ type TransformInterpolationQuery<'w, 's> =
 ///   - otherwise the accumulator advances by `time.delta()`.
 ///
 /// This eliminates the need for a shared `MoveTimer` resource and the inter-
-/// system coordination that came with it.
-fn position_translation(
+/// system coordination that came with it. The resulting progress is
+/// published as [`MoveProgress`] for the debug overlay.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn position_translation(
     mut transforms: TransformInterpolationQuery,
     head_changed: Query<(), (With<SnakeHead>, Changed<Position>)>,
     mut accum: Local<Duration>,
     time: Res<Time>,
     game_state: Res<GameState>,
+    death: Res<DeathSequence>,
+    arena: Res<Arena>,
+    mut move_progress: ResMut<MoveProgress>,
 ) {
     // Outside of `Playing`, snap the accumulator back to zero so the next play
-    // session starts cleanly, and skip the interpolation work entirely.
-    if game_state.phase != GamePhase::Playing {
+    // session starts cleanly, and skip the interpolation work entirely. The
+    // death sequence still lets the snake finish its fatal move, in slow
+    // motion.
+    if game_state.phase != GamePhase::Playing && !death.is_playing() {
         *accum = Duration::ZERO;
+        move_progress.0 = 0.0;
         return;
     }
 
@@ -74,40 +111,190 @@ fn position_translation(
     }
 
     // Calculate interpolation progress (0.0 to 1.0)
-    let progress = (accum.as_secs_f32() / MOVE_INTERVAL.as_secs_f32()).min(1.0);
+    let progress = (accum.as_secs_f32() / arena.move_interval.as_secs_f32()).min(1.0);
+    move_progress.0 = progress;
+    let size = arena.world_size();
 
     for (pos, prev_pos, mut transform) in &mut transforms {
         // Interpolate between previous and current position
-        let curr_x = (pos.x as f32 - ARENA_WIDTH as f32 / 2.0 + 0.5) * CELL_SIZE;
-        let curr_y = (pos.y as f32 - ARENA_HEIGHT as f32 / 2.0 + 0.5) * CELL_SIZE;
-
-        let prev_x = (prev_pos.pos.x as f32 - ARENA_WIDTH as f32 / 2.0 + 0.5) * CELL_SIZE;
-        let prev_y = (prev_pos.pos.y as f32 - ARENA_HEIGHT as f32 / 2.0 + 0.5) * CELL_SIZE;
+        let curr = arena.to_world(*pos);
+        let prev = arena.to_world(prev_pos.pos);
 
         // Handle wrapping for toroidal arena
-        let dx = if (curr_x - prev_x).abs() > CELL_SIZE * ARENA_WIDTH as f32 / 2.0 {
-            if curr_x > prev_x {
-                curr_x - prev_x - CELL_SIZE * ARENA_WIDTH as f32
+        let dx = if (curr.x - prev.x).abs() > size.x / 2.0 {
+            if curr.x > prev.x {
+                curr.x - prev.x - size.x
             } else {
-                curr_x - prev_x + CELL_SIZE * ARENA_WIDTH as f32
+                curr.x - prev.x + size.x
             }
         } else {
-            curr_x - prev_x
+            curr.x - prev.x
         };
 
-        let dy = if (curr_y - prev_y).abs() > CELL_SIZE * ARENA_HEIGHT as f32 / 2.0 {
-            if curr_y > prev_y {
-                curr_y - prev_y - CELL_SIZE * ARENA_HEIGHT as f32
+        let dy = if (curr.y - prev.y).abs() > size.y / 2.0 {
+            if curr.y > prev.y {
+                curr.y - prev.y - size.y
             } else {
-                curr_y - prev_y + CELL_SIZE * ARENA_HEIGHT as f32
+                curr.y - prev.y + size.y
             }
         } else {
-            curr_y - prev_y
+            curr.y - prev.y
         };
 
         // Preserve z (set once at spawn) — only update x/y.
-        transform.translation.x = prev_x + dx * progress;
-        transform.translation.y = prev_y + dy * progress;
+        transform.translation.x = prev.x + dx * progress;
+        transform.translation.y = prev.y + dy * progress;
+    }
+}
+
+// Type alias for the snake parts that may need a wrap-around copy.
+type SnakePartQuery<'w, 's> =
+    Query<'w, 's, (Entity, Has<SnakeHead>), Or<(With<SnakeHead>, With<SnakeSegment>)>>;
+// Type alias for what a wrap-around copy mirrors of its part (and of the
+// part's children).
+type PartLookQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static mut Transform,
+        Option<&'static mut ShapeFill>,
+        Option<&'static mut Visibility>,
+    ),
+>;
+
+/// Draws the entering half of every snake part that crosses a wrap-around
+/// edge.
+///
+/// While `position_translation` slides a part over the edge, a copy of it —
+/// children included, so the head keeps its eyes and tongue — is drawn one
+/// arena width / height away, entering from the opposite side. The arena's
+/// outside mask clips both to the arena, so the part appears to pass
+/// straight through. A copy is spawned when its part enters the edge band
+/// and despawned when it leaves; in between, it follows the part's pose,
+/// color and animation every frame.
+pub(crate) fn sync_wrap_ghosts(
+    mut commands: Commands,
+    arena: Res<Arena>,
+    parts: SnakePartQuery,
+    mut looks: PartLookQuery,
+    children: Query<&Children>,
+    ghosts: Query<(Entity, &WrapGhost)>,
+) {
+    let mut wanted = Vec::new();
+    if !arena.walls {
+        let half = arena.world_size() / 2.0;
+        for (entity, is_head) in &parts {
+            let Ok((transform, ..)) = looks.get(entity) else {
+                continue;
+            };
+            let reach = if is_head { HEAD_REACH } else { SEGMENT_REACH };
+            let center = transform.translation.truncate();
+            // Shift toward the opposite edge on each axis the part pokes out of.
+            let shift = |c: f32, half: f32| {
+                if c.abs() + reach > half {
+                    -c.signum() * 2.0 * half
+                } else {
+                    0.0
+                }
+            };
+            let shift = Vec2::new(shift(center.x, half.x), shift(center.y, half.y));
+            // One copy across each crossed edge, and one across the corner
+            // when both are crossed.
+            let offsets = [Vec2::new(shift.x, 0.0), Vec2::new(0.0, shift.y), shift];
+            for (index, &offset) in offsets.iter().enumerate() {
+                if offset != Vec2::ZERO && !offsets[..index].contains(&offset) {
+                    wanted.push(WrapGhost {
+                        source: entity,
+                        offset,
+                    });
+                }
+            }
+        }
+    }
+
+    for (ghost, &key) in &ghosts {
+        if let Some(index) = wanted.iter().position(|&want| want == key) {
+            wanted.swap_remove(index);
+            mirror_look(
+                &mut looks,
+                &children,
+                key.source,
+                ghost,
+                key.offset.extend(0.0),
+            );
+        } else {
+            commands.entity(ghost).despawn();
+        }
+    }
+
+    for key in wanted {
+        let Ok((transform, ..)) = looks.get(key.source) else {
+            continue;
+        };
+        let ghost_transform = Transform {
+            translation: transform.translation + key.offset.extend(0.0),
+            ..*transform
+        };
+        commands
+            .entity(key.source)
+            .clone_and_spawn_with_opt_out(|builder| {
+                // Copies only look the part: no gameplay or animation
+                // system may pick them up.
+                builder
+                    .deny::<(
+                        SnakeHead,
+                        SnakeSegment,
+                        Position,
+                        PreviousPosition,
+                        GrowingSegment,
+                        SnakeEye,
+                        SnakeTongue,
+                        SegmentOutline,
+                    )>()
+                    .linked_cloning(true);
+            })
+            .insert((key, ghost_transform));
+    }
+}
+
+/// Copies `source`'s transform (moved by `offset`), color and visibility
+/// onto its wrap-around copy `ghost`, then does the same for their children,
+/// which the copy was cloned with in the same order.
+fn mirror_look(
+    looks: &mut PartLookQuery,
+    children: &Query<&Children>,
+    source: Entity,
+    ghost: Entity,
+    offset: Vec3,
+) {
+    let Ok((transform, fill, visibility)) = looks.get(source) else {
+        return;
+    };
+    let transform = Transform {
+        translation: transform.translation + offset,
+        ..*transform
+    };
+    let color = fill.map(|fill| fill.color);
+    let visibility = visibility.copied();
+    let Ok((mut ghost_transform, ghost_fill, ghost_visibility)) = looks.get_mut(ghost) else {
+        return;
+    };
+    ghost_transform.set_if_neq(transform);
+    if let (Some(mut fill), Some(color)) = (ghost_fill, color)
+        && fill.color != color
+    {
+        fill.color = color;
+    }
+    if let (Some(mut ghost_visibility), Some(visibility)) = (ghost_visibility, visibility) {
+        ghost_visibility.set_if_neq(visibility);
+    }
+
+    let (Ok(source_children), Ok(ghost_children)) = (children.get(source), children.get(ghost))
+    else {
+        return;
+    };
+    for (&source, &ghost) in source_children.iter().zip(ghost_children.iter()) {
+        mirror_look(looks, children, source, ghost, Vec3::ZERO);
     }
 }
 
@@ -149,21 +336,67 @@ fn pulse_effect_system(
     }
 }
 
+/// Fades out the reduced-motion eating glow without moving or scaling it.
+fn fade_effect_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut effects: Query<(Entity, &mut FadeEffect, &mut ShapeFill)>,
+) {
+    for (entity, mut effect, mut fill) in effects.iter_mut() {
+        effect.timer.tick(time.delta());
+
+        if effect.timer.is_finished() {
+            commands.entity(entity).despawn();
+        } else {
+            let t = effect.timer.fraction();
+            fill.color.set_alpha(effect.start_alpha * (1.0 - t));
+        }
+    }
+}
+
 /// System to spawn visual effect when food is eaten.
+///
+/// With reduced motion on, the flash, ring and juice burst are replaced by
+/// a soft glow that fades in place. The burst's size follows
+/// [`EffectsSettings::particles`].
 fn spawn_food_eaten_effect(
     mut commands: Commands,
     mut food_eaten_reader: MessageReader<FoodEatenEvent>,
+    arena: Res<Arena>,
+    theme: Res<Theme>,
+    accessibility: Res<AccessibilitySettings>,
+    effects: Res<EffectsSettings>,
 ) {
     for event in food_eaten_reader.read() {
         let radius = CELL_SIZE / 2.0;
-        let x = (event.position.x as f32 - ARENA_WIDTH as f32 / 2.0 + 0.5) * CELL_SIZE;
-        let y = (event.position.y as f32 - ARENA_HEIGHT as f32 / 2.0 + 0.5) * CELL_SIZE;
+        let Vec2 { x, y } = arena.to_world(event.position);
+
+        if accessibility.reduce_motion {
+            let start_alpha = theme.food_eaten.alpha() * 0.6;
+            commands.spawn((
+                ShapeBundle::circle(
+                    &ShapeConfig {
+                        color: theme.food_eaten.with_alpha(start_alpha),
+                        alpha_mode: ShapeAlphaMode::Add,
+                        transform: Transform::from_xyz(x, y, Z_FOOD + 0.5),
+                        ..ShapeConfig::default_2d()
+                    },
+                    radius * 1.2,
+                ),
+                FadeEffect {
+                    timer: Timer::from_seconds(0.5, TimerMode::Once),
+                    start_alpha,
+                },
+            ));
+            spawn_score_popup(&mut commands, &theme, x, y);
+            continue;
+        }
 
         // Main bright flash with HDR color for bloom glow
         commands.spawn((
             ShapeBundle::circle(
                 &ShapeConfig {
-                    color: FOOD_EATEN_COLOR,
+                    color: theme.food_eaten,
                     alpha_mode: ShapeAlphaMode::Add, // Additive blending for bright glow
                     transform: Transform::from_xyz(x, y, Z_FOOD + 0.5),
                     ..ShapeConfig::default_2d()
@@ -181,7 +414,7 @@ fn spawn_food_eaten_effect(
         commands.spawn((
             ShapeBundle::circle(
                 &ShapeConfig {
-                    color: Color::srgba(2.0, 1.5, 0.5, 0.5),
+                    color: theme.eaten_ring,
                     alpha_mode: ShapeAlphaMode::Add,
                     hollow: true,
                     thickness: 3.0,
@@ -200,10 +433,15 @@ fn spawn_food_eaten_effect(
         // Juice burst: little HDR droplets that fly out, slow down, and
         // fade — the main "crunch" feedback for eating an apple.
         let mut rng = rand::rng();
-        for _ in 0..18 {
+        let count = (PARTICLE_COUNT * effects.particles).round() as usize;
+        for _ in 0..count {
             let angle = rng.random_range(0.0..std::f32::consts::TAU);
             let speed = rng.random_range(50.0..170.0);
-            let color = PARTICLE_COLORS[rng.random_range(0..PARTICLE_COLORS.len())];
+            let color = theme
+                .particles
+                .choose(&mut rng)
+                .copied()
+                .unwrap_or(theme.food);
             commands.spawn((
                 ShapeBundle::circle(
                     &ShapeConfig {
@@ -221,23 +459,27 @@ fn spawn_food_eaten_effect(
             ));
         }
 
-        // Floating "+1" over the bite.
-        commands.spawn((
-            Text2d::new("+1"),
-            TextFont {
-                font_size: FontSize::Px(24.0),
-                weight: bevy::text::FontWeight::BOLD,
-                ..default()
-            },
-            TextColor(SCORE_POPUP_COLOR),
-            Transform::from_xyz(x, y + CELL_SIZE * 0.3, Z_FOOD + 0.7),
-            ScorePopup {
-                timer: Timer::from_seconds(0.7, TimerMode::Once),
-            },
-        ));
+        spawn_score_popup(&mut commands, &theme, x, y);
     }
 }
 
+/// Floating "+1" over the bite at `(x, y)`.
+fn spawn_score_popup(commands: &mut Commands, theme: &Theme, x: f32, y: f32) {
+    commands.spawn((
+        Text2d::new("+1"),
+        TextFont {
+            font_size: FontSize::Px(24.0),
+            weight: bevy::text::FontWeight::BOLD,
+            ..default()
+        },
+        TextColor(theme.score_popup),
+        Transform::from_xyz(x, y + CELL_SIZE * 0.3, Z_FOOD + 0.7),
+        ScorePopup {
+            timer: Timer::from_seconds(0.7, TimerMode::Once),
+        },
+    ));
+}
+
 /// Moves, decelerates, shrinks, and fades the food-eaten juice droplets.
 fn particle_update(
     mut commands: Commands,
@@ -284,21 +526,79 @@ fn score_popup_update(
     }
 }
 
-/// System to trigger camera shake on game over.
+/// Marks the cell the snake died on with a red glow: the bitten segment, or
+/// the head where it hit a wall. It stays on the frozen board, through the
+/// death sequence and under the game-over screen, until the next run or the
+/// menu clears it.
+fn highlight_death_cause(
+    mut commands: Commands,
+    mut deaths: MessageReader<DeathCause>,
+    arena: Res<Arena>,
+    theme: Res<Theme>,
+) {
+    for death in deaths.read() {
+        let center = arena.to_world(death.cell).extend(Z_SNAKE_HEAD + 0.4);
+        commands.spawn((
+            ShapeBundle::circle(
+                &ShapeConfig {
+                    color: theme.danger.with_alpha(0.45),
+                    alpha_mode: ShapeAlphaMode::Add,
+                    transform: Transform::from_translation(center),
+                    ..ShapeConfig::default_2d()
+                },
+                CELL_SIZE * 0.75,
+            ),
+            DeathHighlight,
+        ));
+        commands.spawn((
+            ShapeBundle::circle(
+                &ShapeConfig {
+                    color: theme.danger,
+                    alpha_mode: ShapeAlphaMode::Add,
+                    hollow: true,
+                    thickness: 3.0,
+                    transform: Transform::from_translation(center),
+                    ..ShapeConfig::default_2d()
+                },
+                CELL_SIZE * 0.6,
+            ),
+            DeathHighlight,
+        ));
+    }
+}
+
+/// System to trigger camera shake on game over, scaled by
+/// [`EffectsSettings::shake`].
 fn trigger_camera_shake_on_game_over(
     game_state: Res<GameState>,
+    effects: Res<EffectsSettings>,
     mut camera_shake: ResMut<CameraShake>,
 ) {
     // Detect transition to GameOver phase
     if game_state.is_changed() && game_state.phase == GamePhase::GameOver {
         camera_shake.timer = Timer::from_seconds(0.5, TimerMode::Once);
-        camera_shake.intensity = 8.0;
+        camera_shake.intensity = SHAKE_INTENSITY * effects.shake;
+    }
+}
+
+/// Scales the camera's bloom by [`EffectsSettings::bloom`] whenever it
+/// changes.
+fn apply_bloom_strength(effects: Res<EffectsSettings>, mut blooms: Query<&mut Bloom>) {
+    if !effects.is_changed() {
+        return;
+    }
+    for mut bloom in &mut blooms {
+        bloom.intensity = BLOOM_INTENSITY * effects.bloom;
     }
 }
 
-/// System to apply camera shake effect.
+/// System to apply camera shake effect, around the camera's rest position
+/// (`Arena::view_center`). With reduced motion on, the shake still runs its
+/// course but the camera holds still.
 fn camera_shake_system(
     time: Res<Time>,
+    accessibility: Res<AccessibilitySettings>,
+    arena: Res<Arena>,
     mut camera_shake: ResMut<CameraShake>,
     mut camera_query: Query<&mut Transform, With<Camera2d>>,
 ) {
@@ -306,10 +606,11 @@ fn camera_shake_system(
         camera_shake.timer.tick(time.delta());
 
         if let Ok(mut camera_transform) = camera_query.single_mut() {
-            if camera_shake.timer.is_finished() {
-                // Reset camera position when shake is done
-                camera_transform.translation.x = 0.0;
-                camera_transform.translation.y = 0.0;
+            let rest = arena.view_center();
+            if camera_shake.timer.is_finished() || accessibility.reduce_motion {
+                // Reset camera position when shake is done (or disabled)
+                camera_transform.translation.x = rest.x;
+                camera_transform.translation.y = rest.y;
             } else {
                 // Apply random shake based on intensity
                 let progress = camera_shake.timer.fraction();
@@ -319,8 +620,8 @@ fn camera_shake_system(
                 let shake_x = (rng.random::<f32>() - 0.5) * camera_shake.intensity * decay;
                 let shake_y = (rng.random::<f32>() - 0.5) * camera_shake.intensity * decay;
 
-                camera_transform.translation.x = shake_x;
-                camera_transform.translation.y = shake_y;
+                camera_transform.translation.x = rest.x + shake_x;
+                camera_transform.translation.y = rest.y + shake_y;
             }
         }
     }
@@ -0,0 +1,555 @@
+//! The rules of the game, free of any front-end: grid cells, the board and
+//! its edges, how the snake moves, grows and bites itself, and where apples
+//! land. The Bevy game and the terminal build (`src/bin/snake-tui.rs`) both
+//! play by these, so a run plays out the same in either.
+//!
+//! With the `gui` feature the shared types also derive the Bevy component
+//! and resource traits, so the game can use them directly.
+
+use std::collections::{HashSet, VecDeque};
+use std::time::Duration;
+
+use rand::prelude::*;
+
+// Default arena dimensions (the window opens sized for them); the arena of a
+// run is the `Arena` resource.
+pub const ARENA_WIDTH: u32 = 20;
+pub const ARENA_HEIGHT: u32 = 20;
+
+// Timing at the default (`Speed::Normal`) speed
+pub const MOVE_INTERVAL: Duration = Duration::from_millis(150);
+
+/// Maximum number of direction changes that can be queued at once.
+pub const INPUT_BUFFER_CAPACITY: usize = 2;
+
+/// Grid position component for entities on the arena.
+#[cfg_attr(feature = "gui", derive(bevy::prelude::Component))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Position {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Position {
+    /// The neighboring cell in `direction`, before any wrapping.
+    pub fn step(self, direction: Direction) -> Position {
+        let Position { x, y } = self;
+        match direction {
+            Direction::Left => Position { x: x - 1, y },
+            Direction::Right => Position { x: x + 1, y },
+            Direction::Up => Position { x, y: y + 1 },
+            Direction::Down => Position { x, y: y - 1 },
+        }
+    }
+}
+
+/// Initial spawn cell for the snake's head.
+pub const INITIAL_SNAKE_POSITION: Position = Position { x: 3, y: 3 };
+
+/// Direction enum for snake movement.
+#[derive(PartialEq, Eq, Hash, Copy, Clone, Debug)]
+pub enum Direction {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+impl Direction {
+    /// Every direction, in key-binding order.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+    ];
+
+    /// Returns the opposite direction.
+    pub fn opposite(&self) -> Self {
+        match self {
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+        }
+    }
+}
+
+/// Input buffer to queue direction changes.
+#[cfg_attr(feature = "gui", derive(bevy::prelude::Resource))]
+#[derive(Default)]
+pub struct InputBuffer {
+    queued_directions: VecDeque<Direction>,
+}
+
+impl InputBuffer {
+    /// Queue a direction change. Drops the input if the buffer is full
+    /// (capacity is [`INPUT_BUFFER_CAPACITY`]).
+    pub fn queue_direction(&mut self, direction: Direction) {
+        if self.queued_directions.len() < INPUT_BUFFER_CAPACITY {
+            self.queued_directions.push_back(direction);
+        }
+    }
+
+    /// Pop the next queued direction.
+    pub fn pop_direction(&mut self) -> Option<Direction> {
+        self.queued_directions.pop_front()
+    }
+
+    /// The queued directions, next one first.
+    pub fn queued(&self) -> impl Iterator<Item = Direction> + '_ {
+        self.queued_directions.iter().copied()
+    }
+
+    /// Get the last queued direction without removing it.
+    pub fn last_direction(&self) -> Option<Direction> {
+        self.queued_directions.back().copied()
+    }
+
+    /// Clear all queued directions.
+    pub fn clear(&mut self) {
+        self.queued_directions.clear();
+    }
+
+    /// The player asks to go `wanted` while the snake is `heading`. Queues
+    /// it unless it repeats or reverses the direction the snake will be
+    /// going by then (the last queued one, or `heading`).
+    pub fn steer(&mut self, heading: Direction, wanted: Direction) {
+        let last = self.last_direction().unwrap_or(heading);
+        if wanted != last && wanted != last.opposite() {
+            self.queue_direction(wanted);
+        }
+    }
+
+    /// Takes the turn for the next move of a snake `heading` this way, if
+    /// one is queued and it changes the heading.
+    pub fn next_heading(&mut self, heading: Direction) -> Option<Direction> {
+        self.pop_direction().filter(|&turn| turn != heading)
+    }
+}
+
+/// The grid a run is played on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Board {
+    pub width: u32,
+    pub height: u32,
+    /// Leaving the board ends the run instead of wrapping around.
+    pub walls: bool,
+}
+
+impl Default for Board {
+    fn default() -> Self {
+        Board {
+            width: ARENA_WIDTH,
+            height: ARENA_HEIGHT,
+            walls: false,
+        }
+    }
+}
+
+impl Board {
+    /// Whether `position` lies on the board.
+    pub fn contains(&self, position: Position) -> bool {
+        (0..self.width as i32).contains(&position.x)
+            && (0..self.height as i32).contains(&position.y)
+    }
+
+    /// `position` wrapped around the board edges.
+    pub fn wrap(&self, position: Position) -> Position {
+        Position {
+            x: position.x.rem_euclid(self.width as i32),
+            y: position.y.rem_euclid(self.height as i32),
+        }
+    }
+
+    /// Where a head at `head` moves going `direction`: one cell on, wrapped
+    /// around the edges, or `None` when that runs into a wall.
+    pub fn next_head(&self, head: Position, direction: Direction) -> Option<Position> {
+        let next = head.step(direction);
+        if self.walls && !self.contains(next) {
+            return None;
+        }
+        Some(self.wrap(next))
+    }
+
+    /// Whether the snake can move from `a` to `b` in one step, across a
+    /// wrapping edge included.
+    pub fn adjacent(&self, a: Position, b: Position) -> bool {
+        a != b
+            && Direction::ALL
+                .iter()
+                .any(|&direction| self.next_head(a, direction) == Some(b))
+    }
+
+    /// Every cell apples may land on: those not in `occupied`. Sorted by
+    /// `(x, y)`.
+    pub fn free_cells(&self, occupied: &[Position]) -> Vec<Position> {
+        let occupied: HashSet<Position> = occupied.iter().copied().collect();
+        let (width, height) = (self.width as i32, self.height as i32);
+        (0..width)
+            .flat_map(|x| (0..height).map(move |y| Position { x, y }))
+            .filter(|p| !occupied.contains(p))
+            .collect()
+    }
+}
+
+/// Moves the snake (`cells`, head first) one cell: the head goes to `head`
+/// and every other part to the cell of the part in front of it. Returns the
+/// cell the tail left, which is where the snake grows.
+pub fn slither(cells: &mut [Position], head: Position) -> Position {
+    let Some(tail) = cells.last().copied() else {
+        return head;
+    };
+    cells.rotate_right(1);
+    cells[0] = head;
+    tail
+}
+
+/// The index of the body part the head (`snake[0]`) has bitten into, if
+/// any.
+///
+/// Growth appends at the cell the tail just left, so no part ever shares
+/// the head's cell right after growing — a plain equality check suffices.
+pub fn bitten_segment(snake: &[Position]) -> Option<usize> {
+    let (&head, body) = snake.split_first()?;
+    body.iter().position(|&cell| cell == head).map(|i| i + 1)
+}
+
+/// A broken invariant, as found by [`check_invariants`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Violation {
+    /// Part `index` is not next to the part in front of it.
+    Gap { index: usize },
+    /// Two parts share `cell`.
+    Overlap { cell: Position },
+    /// The apple is on the snake.
+    FoodOnSnake { cell: Position },
+    /// The apple is off the board.
+    FoodOffBoard { cell: Position },
+    /// The score is not one point per part behind the head.
+    Score { score: usize, len: usize },
+}
+
+/// Checks what must hold after every move: the snake (head first) is one
+/// unbroken line of parts, no two on the same cell unless `overlap_allowed`
+/// (a run that ended on a self-bite, or Zen's pass-through), the apple is
+/// on a free cell of the board, and the score is the length minus one.
+pub fn check_invariants(
+    board: &Board,
+    snake: &[Position],
+    food: Option<Position>,
+    score: usize,
+    overlap_allowed: bool,
+) -> Result<(), Violation> {
+    if let Some(index) = (1..snake.len()).find(|&i| !board.adjacent(snake[i - 1], snake[i])) {
+        return Err(Violation::Gap { index });
+    }
+    let mut seen = HashSet::new();
+    if let Some(&cell) = snake.iter().find(|&&cell| !seen.insert(cell))
+        && !overlap_allowed
+    {
+        return Err(Violation::Overlap { cell });
+    }
+    if let Some(cell) = food {
+        if snake.contains(&cell) {
+            return Err(Violation::FoodOnSnake { cell });
+        }
+        if !board.contains(cell) {
+            return Err(Violation::FoodOffBoard { cell });
+        }
+    }
+    if score + 1 != snake.len() {
+        return Err(Violation::Score {
+            score,
+            len: snake.len(),
+        });
+    }
+    Ok(())
+}
+
+/// The RNG behind apple placement, reseeded at the start of every run.
+///
+/// Normal runs get a fresh random seed. Daily runs use the date-derived seed
+/// and a *fixed sequence*: each apple first picks a cell anywhere on the
+/// board, then falls forward to the next free cell. That way two players
+/// see the same apples for as long as their snakes don't sit on them —
+/// uniform picking from the free cells would diverge after the first bite.
+#[cfg_attr(feature = "gui", derive(bevy::prelude::Resource))]
+pub struct FoodRng {
+    rng: StdRng,
+    seed: u64,
+    fixed_sequence: bool,
+}
+
+impl Default for FoodRng {
+    fn default() -> Self {
+        FoodRng::random()
+    }
+}
+
+impl FoodRng {
+    /// A fresh, randomly seeded RNG with uniform placement.
+    pub fn random() -> Self {
+        FoodRng::seeded(rand::rng().random())
+    }
+
+    /// The RNG with uniform placement for `seed`, so a run can be replayed.
+    pub fn seeded(seed: u64) -> Self {
+        FoodRng {
+            rng: StdRng::seed_from_u64(seed),
+            seed,
+            fixed_sequence: false,
+        }
+    }
+
+    /// The Daily challenge RNG for `seed`, with fixed-sequence placement.
+    pub fn daily(seed: u64) -> Self {
+        FoodRng {
+            rng: StdRng::seed_from_u64(seed),
+            seed,
+            fixed_sequence: true,
+        }
+    }
+
+    /// The seed this RNG was created from.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Picks the next apple cell on `board` among the cells the snake
+    /// (`occupied`) leaves free, or `None` when no free cell is left.
+    pub fn place(&mut self, board: &Board, occupied: &[Position]) -> Option<Position> {
+        let free = board.free_cells(occupied);
+        if free.is_empty() {
+            return None;
+        }
+        if !self.fixed_sequence {
+            return Some(free[self.rng.random_range(0..free.len())]);
+        }
+        let preferred = Position {
+            x: self.rng.random_range(0..board.width as i32),
+            y: self.rng.random_range(0..board.height as i32),
+        };
+        free.iter()
+            .copied()
+            .find(|p| (p.x, p.y) >= (preferred.x, preferred.y))
+            .or(Some(free[0]))
+    }
+}
+
+/// How a [`Game`] ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    /// The snake hit a wall or bit itself.
+    Crashed,
+    /// The snake fills the board.
+    Won,
+}
+
+/// A whole run by the rules alone, for front-ends without an ECS of their
+/// own. Each [`Game::tick`] makes the same steps, in the same order, as one
+/// move of the Bevy game.
+pub struct Game {
+    pub board: Board,
+    /// The snake's cells, head first.
+    pub snake: Vec<Position>,
+    pub heading: Direction,
+    pub food: Option<Position>,
+    pub score: usize,
+    /// `None` while the run goes on.
+    pub outcome: Option<Outcome>,
+    pub input: InputBuffer,
+    food_rng: FoodRng,
+}
+
+impl Game {
+    /// A new run on `board`: a one-cell snake heading right, and its first
+    /// apple placed by `food_rng`.
+    pub fn new(board: Board, mut food_rng: FoodRng) -> Self {
+        let snake = vec![INITIAL_SNAKE_POSITION];
+        let food = food_rng.place(&board, &snake);
+        Game {
+            board,
+            snake,
+            heading: Direction::Right,
+            food,
+            score: 0,
+            outcome: None,
+            input: InputBuffer::default(),
+            food_rng,
+        }
+    }
+
+    /// The player asks to go `wanted`; see [`InputBuffer::steer`].
+    pub fn steer(&mut self, wanted: Direction) {
+        self.input.steer(self.heading, wanted);
+    }
+
+    /// Checks [`check_invariants`] on the current state.
+    pub fn check(&self) -> Result<(), Violation> {
+        let bitten = self.outcome == Some(Outcome::Crashed);
+        check_invariants(&self.board, &self.snake, self.food, self.score, bitten)
+    }
+
+    /// Makes one move: turn, move, eat, grow, then check for a self-bite.
+    /// Does nothing once the run is over.
+    pub fn tick(&mut self) {
+        if self.outcome.is_some() {
+            return;
+        }
+        if let Some(turn) = self.input.next_heading(self.heading) {
+            self.heading = turn;
+        }
+        let Some(head) = self.board.next_head(self.snake[0], self.heading) else {
+            self.outcome = Some(Outcome::Crashed);
+            return;
+        };
+        let vacated = slither(&mut self.snake, head);
+
+        if self.food == Some(head) {
+            self.score += 1;
+            // Grow before placing the next apple, which must not land on
+            // the new tail.
+            self.snake.push(vacated);
+            self.food = self.food_rng.place(&self.board, &self.snake);
+            if self.food.is_none() {
+                self.outcome = Some(Outcome::Won);
+            }
+        }
+
+        if self.outcome.is_none() && bitten_segment(&self.snake).is_some() {
+            self.outcome = Some(Outcome::Crashed);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn board(width: u32, height: u32, walls: bool) -> Board {
+        Board {
+            width,
+            height,
+            walls,
+        }
+    }
+
+    #[test]
+    fn slither_follows_the_head_and_frees_the_tail() {
+        let mut cells = [
+            Position { x: 2, y: 0 },
+            Position { x: 1, y: 0 },
+            Position { x: 0, y: 0 },
+        ];
+        let vacated = slither(&mut cells, Position { x: 2, y: 1 });
+        assert_eq!(vacated, Position { x: 0, y: 0 });
+        assert_eq!(
+            cells,
+            [
+                Position { x: 2, y: 1 },
+                Position { x: 2, y: 0 },
+                Position { x: 1, y: 0 },
+            ]
+        );
+    }
+
+    #[test]
+    fn edges_wrap_unless_walled() {
+        let corner = Position { x: 4, y: 0 };
+        assert_eq!(
+            board(5, 5, false).next_head(corner, Direction::Right),
+            Some(Position { x: 0, y: 0 })
+        );
+        assert_eq!(
+            board(5, 5, false).next_head(corner, Direction::Down),
+            Some(Position { x: 4, y: 4 })
+        );
+        assert_eq!(board(5, 5, true).next_head(corner, Direction::Right), None);
+    }
+
+    #[test]
+    fn steering_never_reverses_into_the_body() {
+        let mut input = InputBuffer::default();
+        input.steer(Direction::Right, Direction::Left);
+        input.steer(Direction::Right, Direction::Right);
+        assert_eq!(input.last_direction(), None);
+
+        // Up, then Down (a reversal of the queued Up) is dropped.
+        input.steer(Direction::Right, Direction::Up);
+        input.steer(Direction::Right, Direction::Down);
+        assert_eq!(input.next_heading(Direction::Right), Some(Direction::Up));
+        assert_eq!(input.next_heading(Direction::Up), None);
+    }
+
+    #[test]
+    fn eating_grows_the_snake_and_a_full_board_wins() {
+        let board = board(3, 1, true);
+        let mut game = Game::new(board, FoodRng::daily(7));
+        game.snake = vec![Position { x: 0, y: 0 }];
+        game.food = Some(Position { x: 1, y: 0 });
+
+        game.tick();
+        assert_eq!(game.score, 1);
+        assert_eq!(
+            game.snake,
+            [Position { x: 1, y: 0 }, Position { x: 0, y: 0 }]
+        );
+        assert_eq!(game.food, Some(Position { x: 2, y: 0 }));
+
+        game.tick();
+        assert_eq!(game.score, 2);
+        assert_eq!(game.snake.len(), 3);
+        assert_eq!(game.outcome, Some(Outcome::Won));
+    }
+
+    /// The way to the apple along one axis, for the autopilot steps below.
+    fn toward_food(game: &Game) -> Option<Direction> {
+        let (head, food) = (game.snake[0], game.food?);
+        Some(if food.x > head.x {
+            Direction::Right
+        } else if food.x < head.x {
+            Direction::Left
+        } else if food.y > head.y {
+            Direction::Up
+        } else {
+            Direction::Down
+        })
+    }
+
+    /// A tick's worth of input: a key, or (`None`) a turn toward the apple,
+    /// so runs get long enough to grow, wrap and fill small boards.
+    fn steps() -> impl Strategy<Value = Vec<Option<Direction>>> {
+        let key = prop::sample::select(Direction::ALL.to_vec());
+        prop::collection::vec(prop::option::weighted(0.4, key), 0..400)
+    }
+
+    proptest! {
+        /// Boards from 4×4, the smallest the start cell (3, 3) fits on.
+        #[test]
+        fn every_tick_keeps_the_invariants(
+            width in 4u32..=10,
+            height in 4u32..=10,
+            walls: bool,
+            seed: u64,
+            daily: bool,
+            steps in steps(),
+        ) {
+            let food_rng = if daily { FoodRng::daily(seed) } else { FoodRng::seeded(seed) };
+            let mut game = Game::new(board(width, height, walls), food_rng);
+            prop_assert_eq!(game.check(), Ok(()));
+            for step in steps {
+                if let Some(direction) = step.or_else(|| toward_food(&game)) {
+                    game.steer(direction);
+                }
+                game.tick();
+                prop_assert_eq!(game.check(), Ok(()), "after a tick of {:?}", step);
+                if game.outcome.is_some() {
+                    break;
+                }
+            }
+        }
+    }
+}
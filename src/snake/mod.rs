@@ -1,25 +1,33 @@
 //! Snake plugin - handles snake movement, input, collision detection, and spawning.
 
-use bevy::{ecs::system::ParamSet, prelude::*, time::common_conditions::on_timer};
+use bevy::{ecs::system::ParamSet, prelude::*};
 use bevy_vector_shapes::prelude::*;
+use snake_bevy::rules::{Board, bitten_segment, slither};
+use std::time::Duration;
 
 use crate::game::{
-    ARENA_HEIGHT, ARENA_WIDTH, CELL_SIZE, Direction, GamePhase, GameSet, GameState,
-    GrowingSegment, GrowthEvent, INITIAL_SNAKE_POSITION, InputBuffer, MOVE_INTERVAL, Position,
-    PreviousPosition, SNAKE_HEAD_COLOR, SNAKE_SEGMENT_COLOR, SnakeEye, SnakeHead, SnakeSegment,
-    SnakeTongue, TONGUE_COLOR, Z_SNAKE_HEAD, Z_SNAKE_SEGMENT,
+    AccessibilitySettings, Arena, BodyStyle, Burst, CELL_SIZE, DeathCause, DeathKind, Direction,
+    GameMode, GamePhase, GameSet, GameState, GrowingSegment, GrowthEvent, INITIAL_SNAKE_POSITION,
+    InputBuffer, KeyBindings, Position, PreviousPosition, SegmentOutline, SnakeEye, SnakeHead,
+    SnakeSegment, SnakeTongue, ThemeColor, ThemeSettings, TurnEvent, Z_SNAKE_HEAD, Z_SNAKE_SEGMENT,
+    ZenBite, ZenSettings,
 };
+use crate::rendering::{position_translation, sync_wrap_ghosts};
+use crate::theme::Theme;
 
 // Visual sizing: head fills almost the full cell so it reads as larger than
 // the body, and the body sits inside its cell so adjacent segments show a
 // visible gap (~14% of CELL_SIZE).
-const HEAD_SIZE_FACTOR: f32 = 0.92;
-const SEGMENT_SIZE_FACTOR: f32 = 0.86;
+pub(crate) const HEAD_SIZE_FACTOR: f32 = 0.92;
+pub(crate) const SEGMENT_SIZE_FACTOR: f32 = 0.86;
 
 // Corner rounding, normalized to the shape's half-size (0.0 = square,
 // 1.0 = pill). The head is rounder than the body so it reads as a snout.
-const HEAD_ROUNDING: f32 = 0.7;
-const SEGMENT_ROUNDING: f32 = 0.55;
+pub(crate) const HEAD_ROUNDING: f32 = 0.7;
+pub(crate) const SEGMENT_ROUNDING: f32 = 0.55;
+
+/// Stroke width of the high-contrast body outline, in pixels.
+pub(crate) const OUTLINE_THICKNESS: f32 = 2.5;
 
 // Tongue geometry (local pixel space of the head, +x = forward).
 const TONGUE_LENGTH: f32 = CELL_SIZE * 0.42;
@@ -31,23 +39,24 @@ const TONGUE_FORK_ANGLE: f32 = 0.20;
 // snake doesn't end abruptly. Index 0 is the tail itself.
 const TAIL_TAPER: [f32; 3] = [0.65, 0.78, 0.90];
 
-// Body color gradient. Each body segment's color is interpolated between
-// `BODY_COLOR_NEAR_HEAD` (segment closest to the head) and
-// `BODY_COLOR_NEAR_TAIL` (the tail) based on its position in the snake.
-// Same hue as `SNAKE_SEGMENT_COLOR`, just brighter near the head and dimmer
-// at the tail. The tail color is intentionally close to the playfield's
-// background so the tail visually dissolves into the arena.
-const BODY_COLOR_NEAR_HEAD: Color = Color::srgba(0.40, 0.95, 0.40, 1.0);
-const BODY_COLOR_NEAR_TAIL: Color = Color::srgba(0.08, 0.28, 0.08, 1.0);
+// Body color gradient. Each body segment's color is interpolated between the
+// theme's `body_near_head` (segment closest to the head) and
+// `body_near_tail` (the tail) based on its position in the snake. The
+// built-in themes keep the tail color close to the arena's so the tail
+// visually dissolves into the playfield.
 
 /// Exponent applied to the gradient parameter `t` (in 0.0..=1.0).
 ///
 /// A value > 1 gives a concave-up curve: segments near the head stay close
-/// to `BODY_COLOR_NEAR_HEAD`, then darken faster as they approach the tail.
+/// to `body_near_head`, then darken faster as they approach the tail.
 /// Visually: a bright, even body up front and a fade that accelerates into
 /// the tail — much more readable per-segment than a linear ramp.
 const BODY_GRADIENT_EXPONENT: f32 = 1.6;
 
+/// Straight pieces each tube link is drawn in, so width and color ease
+/// from one segment to the next instead of stepping at the joint.
+const TUBE_STEPS: usize = 4;
+
 /// Plugin for snake-related systems.
 pub struct SnakePlugin;
 
@@ -58,14 +67,16 @@ impl Plugin for SnakePlugin {
         // `position_translation` itself (no shared resource needed).
         app.add_systems(
             Update,
-            (snake_movement_input, snake_movement.run_if(on_timer(MOVE_INTERVAL)))
+            (snake_movement_input, snake_movement.run_if(move_tick))
                 .chain()
                 .in_set(GameSet::Movement),
         );
         // Growth and game-over run after food collision (GameSet::Effects).
         app.add_systems(
             Update,
-            (snake_growth, game_over_check).chain().in_set(GameSet::Effects),
+            (snake_growth, game_over_check)
+                .chain()
+                .in_set(GameSet::Effects),
         );
         // Visual body styling (tail taper + head→tail color gradient) belongs
         // in the Rendering set so it runs after `growing_segment_animation`
@@ -75,20 +86,21 @@ impl Plugin for SnakePlugin {
             Update,
             (style_snake_body, animate_tongue, blink_eyes).in_set(GameSet::Rendering),
         );
+        // The tube follows the styled, interpolated segments, and hides them
+        // before the wrap copies are cloned from them.
+        app.add_systems(
+            Update,
+            draw_snake_tube
+                .after(position_translation)
+                .after(style_snake_body)
+                .before(sync_wrap_ghosts)
+                .in_set(GameSet::Rendering),
+        );
     }
 }
 
 // Type aliases for complex queries
-type SnakeHeadQuery<'w, 's> = Query<
-    'w,
-    's,
-    (
-        Entity,
-        &'static mut SnakeHead,
-        &'static mut Position,
-        &'static mut PreviousPosition,
-    ),
->;
+type SnakeHeadQuery<'w, 's> = Query<'w, 's, (&'static mut SnakeHead, &'static Position)>;
 type PositionQuery<'w, 's> = Query<'w, 's, (&'static mut Position, &'static mut PreviousPosition)>;
 type BodyStyleQuery<'w, 's> = Query<
     'w,
@@ -96,16 +108,20 @@ type BodyStyleQuery<'w, 's> = Query<
     (&'static mut Transform, &'static mut ShapeFill),
     (With<SnakeSegment>, Without<GrowingSegment>),
 >;
+type TubePointQuery<'w, 's> =
+    Query<'w, 's, (&'static Transform, &'static ShapeFill), Without<Burst>>;
 
 /// Spawns the snake head entity with eyes and a forked tongue.
 ///
-/// The head is colored in HDR-green (matches the body's hue but pushed past
-/// 1.0 so the bloom pass picks it up — no separate "glow disc" child needed).
+/// The head is colored with the theme's `snake_head` (in the default theme an
+/// HDR green that matches the body's hue but is pushed past 1.0 so the bloom
+/// pass picks it up — no separate "glow disc" child needed).
 /// Eyes and tongue live in the head's local space with +x = forward (the
 /// head spawns facing `Right`; `update_head_rotation` rotates the children
 /// to follow).
-pub fn spawn_snake_head(commands: &mut Commands) -> Entity {
+pub fn spawn_snake_head(commands: &mut Commands, arena: &Arena, theme: &Theme) -> Entity {
     let size = CELL_SIZE * HEAD_SIZE_FACTOR;
+    let start = arena.to_world(INITIAL_SNAKE_POSITION);
 
     // Eye geometry, in the head's local pixel space.
     //   forward: pushed toward the front (positive x = "Right" direction)
@@ -119,15 +135,9 @@ pub fn spawn_snake_head(commands: &mut Commands) -> Entity {
         .spawn((
             ShapeBundle::rect(
                 &ShapeConfig {
-                    color: SNAKE_HEAD_COLOR,
+                    color: theme.snake_head,
                     corner_radii: Vec4::splat(HEAD_ROUNDING),
-                    transform: Transform::from_xyz(
-                        (INITIAL_SNAKE_POSITION.x as f32 - ARENA_WIDTH as f32 / 2.0 + 0.5)
-                            * CELL_SIZE,
-                        (INITIAL_SNAKE_POSITION.y as f32 - ARENA_HEIGHT as f32 / 2.0 + 0.5)
-                            * CELL_SIZE,
-                        Z_SNAKE_HEAD,
-                    ),
+                    transform: Transform::from_translation(start.extend(Z_SNAKE_HEAD)),
                     ..ShapeConfig::default_2d()
                 },
                 Vec2::splat(size),
@@ -135,39 +145,40 @@ pub fn spawn_snake_head(commands: &mut Commands) -> Entity {
             SnakeHead {
                 direction: Direction::Right,
             },
+            ThemeColor::SnakeHead,
             INITIAL_SNAKE_POSITION,
             PreviousPosition {
                 pos: INITIAL_SNAKE_POSITION,
             },
         ))
         .with_children(|head| {
-            // Eyes: white sclera with a black pupil set slightly forward,
+            // Eyes: a sclera with a pupil set slightly forward,
             // so the snake looks where it's going. The pupil is a child of
             // the sclera and rides its blink squash.
             for side in [1.0_f32, -1.0] {
                 head.spawn((
                     ShapeBundle::circle(
                         &ShapeConfig {
-                            color: Color::srgba(0.95, 0.95, 0.9, 1.0),
-                            transform: Transform::from_xyz(
-                                eye_forward,
-                                side * eye_lateral,
-                                0.1,
-                            ),
+                            color: theme.eye,
+                            transform: Transform::from_xyz(eye_forward, side * eye_lateral, 0.1),
                             ..ShapeConfig::default_2d()
                         },
                         sclera_radius,
                     ),
                     SnakeEye,
+                    ThemeColor::Eye,
                 ))
                 .with_children(|eye| {
-                    eye.spawn(ShapeBundle::circle(
-                        &ShapeConfig {
-                            color: Color::srgba(0.02, 0.02, 0.02, 1.0),
-                            transform: Transform::from_xyz(CELL_SIZE * 0.05, 0.0, 0.01),
-                            ..ShapeConfig::default_2d()
-                        },
-                        pupil_radius,
+                    eye.spawn((
+                        ShapeBundle::circle(
+                            &ShapeConfig {
+                                color: theme.pupil,
+                                transform: Transform::from_xyz(CELL_SIZE * 0.05, 0.0, 0.01),
+                                ..ShapeConfig::default_2d()
+                            },
+                            pupil_radius,
+                        ),
+                        ThemeColor::Pupil,
                     ));
                 });
             }
@@ -178,7 +189,7 @@ pub fn spawn_snake_head(commands: &mut Commands) -> Entity {
                 head.spawn((
                     ShapeBundle::rect(
                         &ShapeConfig {
-                            color: TONGUE_COLOR,
+                            color: theme.tongue,
                             corner_radii: Vec4::splat(1.0),
                             transform: Transform::from_xyz(size / 2.0, 0.0, -0.05)
                                 .with_scale(Vec3::new(0.0, 1.0, 1.0)),
@@ -187,6 +198,7 @@ pub fn spawn_snake_head(commands: &mut Commands) -> Entity {
                         Vec2::new(TONGUE_LENGTH, TONGUE_WIDTH),
                     ),
                     SnakeTongue { side },
+                    ThemeColor::Tongue,
                 ));
             }
         })
@@ -197,22 +209,28 @@ pub fn spawn_snake_head(commands: &mut Commands) -> Entity {
 ///
 /// Sized below the cell so adjacent segments leave a small visible gap —
 /// the body reads as a chain of pills rather than a continuous rectangle.
-pub fn spawn_snake_segment(commands: &mut Commands, position: Position) -> Entity {
+/// Each segment carries a hidden [`SegmentOutline`] child that
+/// `style_snake_body` reveals in high-contrast mode.
+pub fn spawn_snake_segment(
+    commands: &mut Commands,
+    position: Position,
+    arena: &Arena,
+    theme: &Theme,
+) -> Entity {
     let size = CELL_SIZE * SEGMENT_SIZE_FACTOR;
 
     // Compute world-space spawn coordinates so the segment renders at the right
     // z-layer immediately. `position_translation` will overwrite x/y next frame
     // but preserve z.
-    let world_x = (position.x as f32 - ARENA_WIDTH as f32 / 2.0 + 0.5) * CELL_SIZE;
-    let world_y = (position.y as f32 - ARENA_HEIGHT as f32 / 2.0 + 0.5) * CELL_SIZE;
+    let world = arena.to_world(position);
 
     commands
         .spawn((
             ShapeBundle::rect(
                 &ShapeConfig {
-                    color: SNAKE_SEGMENT_COLOR,
+                    color: theme.snake_segment,
                     corner_radii: Vec4::splat(SEGMENT_ROUNDING),
-                    transform: Transform::from_xyz(world_x, world_y, Z_SNAKE_SEGMENT),
+                    transform: Transform::from_translation(world.extend(Z_SNAKE_SEGMENT)),
                     ..ShapeConfig::default_2d()
                 },
                 Vec2::splat(size),
@@ -221,36 +239,71 @@ pub fn spawn_snake_segment(commands: &mut Commands, position: Position) -> Entit
             position,
             PreviousPosition { pos: position },
         ))
+        .with_children(|segment| {
+            segment
+                .spawn((
+                    ShapeBundle::rect(
+                        &ShapeConfig {
+                            color: theme.outline(),
+                            corner_radii: Vec4::splat(SEGMENT_ROUNDING),
+                            hollow: true,
+                            thickness: OUTLINE_THICKNESS,
+                            transform: Transform::from_xyz(0.0, 0.0, 0.01),
+                            ..ShapeConfig::default_2d()
+                        },
+                        Vec2::splat(size),
+                    ),
+                    SegmentOutline,
+                    ThemeColor::Outline,
+                ))
+                .insert(Visibility::Hidden);
+        })
         .id()
 }
 
 /// Maps the current keyboard state to a [`Direction`], falling back to
-/// `current` when no directional key is held.
+/// `current` when no directional key is held. The arrow keys always steer;
+/// [`KeyBindings`] adds the player's own keys (WASD by default).
 ///
 /// Lives here rather than on `Direction` itself because it depends on a Bevy
 /// input resource — a concern that doesn't belong on a plain data enum.
-fn direction_from_input(keyboard_input: &ButtonInput<KeyCode>, current: Direction) -> Direction {
+fn direction_from_input(
+    keyboard_input: &ButtonInput<KeyCode>,
+    keys: &KeyBindings,
+) -> Option<Direction> {
     // `just_pressed` is checked in addition to `pressed` so a tap whose
     // key-up lands in the same frame as its key-down still registers
     // (possible with very fast taps or synthetic input on the web build).
     let down = |key: KeyCode| keyboard_input.pressed(key) || keyboard_input.just_pressed(key);
 
-    if down(KeyCode::ArrowLeft) || down(KeyCode::KeyA) {
-        Direction::Left
-    } else if down(KeyCode::ArrowRight) || down(KeyCode::KeyD) {
-        Direction::Right
-    } else if down(KeyCode::ArrowUp) || down(KeyCode::KeyW) {
-        Direction::Up
-    } else if down(KeyCode::ArrowDown) || down(KeyCode::KeyS) {
-        Direction::Down
-    } else {
-        current
+    [
+        (Direction::Left, KeyCode::ArrowLeft),
+        (Direction::Right, KeyCode::ArrowRight),
+        (Direction::Up, KeyCode::ArrowUp),
+        (Direction::Down, KeyCode::ArrowDown),
+    ]
+    .into_iter()
+    .find(|&(direction, arrow)| down(arrow) || down(keys.key(direction)))
+    .map(|(direction, _)| direction)
+}
+
+/// Run condition that fires once every [`Arena::move_interval`], so the
+/// snake's speed follows the current run's settings.
+fn move_tick(time: Res<Time>, arena: Res<Arena>, mut elapsed: Local<Duration>) -> bool {
+    *elapsed += time.delta();
+    if *elapsed < arena.move_interval {
+        return false;
     }
+    *elapsed -= arena.move_interval;
+    // Never queue up a burst of moves after a long frame.
+    *elapsed = (*elapsed).min(arena.move_interval);
+    true
 }
 
 /// System to read keyboard input and queue direction changes.
 fn snake_movement_input(
     keyboard_input: Res<ButtonInput<KeyCode>>,
+    keys: Res<KeyBindings>,
     mut input_buffer: ResMut<InputBuffer>,
     heads: Query<&SnakeHead>,
     game_state: Res<GameState>,
@@ -261,86 +314,72 @@ fn snake_movement_input(
 
     let Ok(head) = heads.single() else { return };
 
-    // Get the last direction in buffer or current head direction
-    let last_direction = input_buffer.last_direction().unwrap_or(head.direction);
-
-    // Get new direction from input
-    let new_direction = direction_from_input(&keyboard_input, last_direction);
-
-    // If direction changed and it's not opposite to the last direction, queue it
-    if new_direction != last_direction && new_direction != last_direction.opposite() {
-        input_buffer.queue_direction(new_direction);
+    // Queued unless it repeats or reverses the last queued direction.
+    if let Some(wanted) = direction_from_input(&keyboard_input, &keys) {
+        input_buffer.steer(head.direction, wanted);
     }
 }
 
-/// System to execute snake movement on a timer.
+/// System to execute snake movement on each move tick.
+///
+/// The step itself is the shared rules' (`Board::next_head` and `slither`);
+/// this writes the result back to the parts, keeping each one's previous
+/// cell for interpolation. The head wraps around the arena edges, unless the
+/// run has walls: then leaving the arena ends the run (Zen, which can't die,
+/// always wraps).
 fn snake_movement(
-    game_state: Res<GameState>,
+    mut game_state: ResMut<GameState>,
+    arena: Res<Arena>,
+    game_mode: Res<GameMode>,
     mut input_buffer: ResMut<InputBuffer>,
+    mut turn_writer: MessageWriter<TurnEvent>,
+    mut death_writer: MessageWriter<DeathCause>,
     mut query_set: ParamSet<(SnakeHeadQuery, PositionQuery)>,
 ) {
     if game_state.phase != GamePhase::Playing {
         return;
     }
 
-    // Step 1: Get the head entity and its current direction and position.
-    let (head_entity, head_direction, head_position) = {
+    // Step 1: Consume a buffered turn and work out where the head goes.
+    let head_position = {
         let mut heads_query = query_set.p0();
-        let Ok((entity, mut head, position, _)) = heads_query.single_mut() else {
+        let Ok((mut head, position)) = heads_query.single_mut() else {
             return;
         };
-        // Try to consume buffered direction
-        if let Some(buffered_direction) = input_buffer.pop_direction() {
-            head.direction = buffered_direction;
-        }
-        (entity, head.direction, *position)
-    };
-
-    // Step 2: Record the current position of each segment before any movement
-    let segments_positions = {
-        let mut positions = Vec::new();
-        let positions_query = query_set.p1();
-
-        for &segment_entity in &game_state.snake_segments {
-            if segment_entity == head_entity {
-                positions.push(head_position);
-            } else if let Ok((segment_pos, _)) = positions_query.get(segment_entity) {
-                positions.push(*segment_pos);
-            }
+        if let Some(turn) = input_buffer.next_heading(head.direction) {
+            head.direction = turn;
+            turn_writer.write(TurnEvent { direction: turn });
         }
-        positions
-    };
-
-    // Step 3: Move the head in the current direction
-    {
-        let mut heads_query = query_set.p0();
-        let Ok((_, _, mut head_pos, mut prev_pos)) = heads_query.single_mut() else {
+        let board = Board {
+            walls: arena.walls && *game_mode != GameMode::Zen,
+            ..arena.board()
+        };
+        let Some(next) = board.next_head(*position, head.direction) else {
+            // Crashed into a wall: the snake stops where it is.
+            game_state.phase = GamePhase::GameOver;
+            death_writer.write(DeathCause {
+                kind: DeathKind::Wall,
+                cell: *position,
+            });
+            info!("Hit a wall! Final score: {}", game_state.score);
             return;
         };
-        // Save current position as previous position for interpolation
-        prev_pos.pos = *head_pos;
-
-        // Move the head one cell in the current direction
-        match head_direction {
-            Direction::Left => head_pos.x -= 1,
-            Direction::Right => head_pos.x += 1,
-            Direction::Up => head_pos.y += 1,
-            Direction::Down => head_pos.y -= 1,
-        }
-
-        // Wrap around if the snake goes off the edge (creates a toroidal arena)
-        head_pos.x = (head_pos.x + ARENA_WIDTH as i32) % ARENA_WIDTH as i32;
-        head_pos.y = (head_pos.y + ARENA_HEIGHT as i32) % ARENA_HEIGHT as i32;
-    }
+        next
+    };
 
-    // Step 4: Move each body segment to the position of the segment in front of it
-    {
-        let mut positions_query = query_set.p1();
-        for (i, segment_entity) in game_state.snake_segments.iter().skip(1).enumerate() {
-            if let Ok((mut segment_pos, mut prev_pos)) = positions_query.get_mut(*segment_entity) {
-                prev_pos.pos = *segment_pos;
-                *segment_pos = segments_positions[i];
-            }
+    // Step 2: Slide every part into the cell of the part in front of it.
+    let mut positions_query = query_set.p1();
+    let mut cells: Vec<Position> = game_state
+        .snake_segments
+        .iter()
+        .filter_map(|&entity| positions_query.get(entity).ok().map(|(pos, _)| *pos))
+        .collect();
+    slither(&mut cells, head_position);
+
+    for (&entity, cell) in game_state.snake_segments.iter().zip(cells) {
+        if let Ok((mut pos, mut prev_pos)) = positions_query.get_mut(entity) {
+            prev_pos.pos = *pos;
+            *pos = cell;
         }
     }
 }
@@ -357,6 +396,8 @@ fn snake_growth(
     mut commands: Commands,
     mut game_state: ResMut<GameState>,
     mut growth_reader: MessageReader<GrowthEvent>,
+    arena: Res<Arena>,
+    theme: Res<Theme>,
     prev_positions: Query<&PreviousPosition>,
 ) {
     for _ in growth_reader.read() {
@@ -367,7 +408,7 @@ fn snake_growth(
             return;
         };
 
-        let new_segment = spawn_snake_segment(&mut commands, last_prev.pos);
+        let new_segment = spawn_snake_segment(&mut commands, last_prev.pos, &arena, &theme);
 
         // Add growing animation component
         commands.entity(new_segment).insert(GrowingSegment {
@@ -378,28 +419,49 @@ fn snake_growth(
     }
 }
 
-/// System to check for game over (self-collision).
+/// System to check for self-collision and apply the active mode's rule.
+///
+/// Every mode but [`GameMode::Zen`] ends the run, writing a [`DeathCause`]
+/// for the bitten segment. In Zen the
+/// [`ZenSettings`] bite rule decides: pass straight through, or cut the body
+/// off at the bitten segment (despawning it and everything behind it).
 fn game_over_check(
+    mut commands: Commands,
     mut game_state: ResMut<GameState>,
-    head_positions: Query<&Position, With<SnakeHead>>,
-    segment_positions: Query<&Position, With<SnakeSegment>>,
+    game_mode: Res<GameMode>,
+    zen: Res<ZenSettings>,
+    positions: Query<&Position>,
+    mut death_writer: MessageWriter<DeathCause>,
 ) {
     if game_state.phase != GamePhase::Playing {
         return;
     }
 
-    let Ok(head_pos) = head_positions.single() else {
+    let cells: Vec<Position> = game_state
+        .snake_segments
+        .iter()
+        .filter_map(|&entity| positions.get(entity).ok().copied())
+        .collect();
+    let Some(bitten) = bitten_segment(&cells) else {
         return;
     };
 
-    // Because new segments now spawn at the tail's previous position
-    // (see `snake_growth`), no body segment can ever share the head's cell
-    // immediately after a growth — so a plain equality check is sufficient.
-    for segment_pos in segment_positions.iter() {
-        if head_pos == segment_pos {
+    match (*game_mode, zen.on_self_bite) {
+        (GameMode::Zen, ZenBite::PassThrough) => {}
+        (GameMode::Zen, ZenBite::CutTail) => {
+            for entity in game_state.snake_segments.drain(bitten..) {
+                commands.entity(entity).despawn();
+            }
+            // Keep the score in step with the length: one point per segment.
+            game_state.score = game_state.snake_segments.len() - 1;
+        }
+        _ => {
             game_state.phase = GamePhase::GameOver;
+            death_writer.write(DeathCause {
+                kind: DeathKind::SelfBite { segment: bitten },
+                cell: cells[bitten],
+            });
             info!("Game Over! Final score: {}", game_state.score);
-            break;
         }
     }
 }
@@ -476,6 +538,42 @@ fn lerp_color(a: Color, b: Color, t: f32) -> Color {
     )
 }
 
+/// Resting scale and color of body segment `body_index` (0 = next to the
+/// head) in a body of `body_count` segments: the tail taper and the
+/// head→tail gradient, without the breathing wave. Shared with the SVG
+/// export so pictures match the game.
+pub(crate) fn body_segment_look(
+    body_index: usize,
+    body_count: usize,
+    theme: &Theme,
+    high_contrast: bool,
+) -> (f32, Color) {
+    let from_tail = body_count - 1 - body_index;
+    // Scale taper for the last few segments; full scale otherwise.
+    let taper = TAIL_TAPER.get(from_tail).copied().unwrap_or(1.0);
+
+    // Color gradient: t = 0.0 at the segment closest to the head,
+    // t = 1.0 at the tail. Single-segment body collapses to t = 0.0.
+    // The exponent biases the curve so brightness change-per-segment is
+    // small near the head and large near the tail — perceptually clearer
+    // than a linear ramp on long snakes.
+    let t_linear = if body_count <= 1 {
+        0.0
+    } else {
+        body_index as f32 / (body_count - 1) as f32
+    };
+    // High contrast keeps the whole body at full brightness.
+    let t = if high_contrast {
+        0.0
+    } else {
+        t_linear.powf(BODY_GRADIENT_EXPONENT)
+    };
+    (
+        taper,
+        lerp_color(theme.body_near_head, theme.body_near_tail, t),
+    )
+}
+
 /// Per-frame visual styling for snake body segments: applies the tail
 /// scale-taper and the head→tail color gradient.
 ///
@@ -490,7 +588,26 @@ fn lerp_color(a: Color, b: Color, t: f32) -> Color {
 /// On top of the taper, a slow "breathing" wave travels from head to tail
 /// (a few percent of scale, phase-shifted per segment) so the body reads as
 /// one living organism rather than a chain of static tiles.
-fn style_snake_body(time: Res<Time>, game_state: Res<GameState>, mut segments: BodyStyleQuery) {
+///
+/// In high-contrast mode the gradient is skipped (the tail never fades) and
+/// every segment's [`SegmentOutline`] is shown.
+fn style_snake_body(
+    time: Res<Time>,
+    game_state: Res<GameState>,
+    theme: Res<Theme>,
+    accessibility: Res<AccessibilitySettings>,
+    mut segments: BodyStyleQuery,
+    mut outlines: Query<&mut Visibility, With<SegmentOutline>>,
+) {
+    let outline_visibility = if accessibility.high_contrast {
+        Visibility::Inherited
+    } else {
+        Visibility::Hidden
+    };
+    for mut visibility in &mut outlines {
+        visibility.set_if_neq(outline_visibility);
+    }
+
     let total = game_state.snake_segments.len();
     if total < 2 {
         return; // only the head — no body to style.
@@ -501,26 +618,16 @@ fn style_snake_body(time: Res<Time>, game_state: Res<GameState>, mut segments: B
     for (i, &entity) in game_state.snake_segments.iter().enumerate().skip(1) {
         // Position in body, 0 = closest to head, body_count - 1 = tail.
         let body_index = i - 1;
-        let from_tail = body_count - 1 - body_index;
-
-        // Scale taper for the last few segments; full scale otherwise.
-        let taper = TAIL_TAPER.get(from_tail).copied().unwrap_or(1.0);
-        // Breathing wave traveling down the body.
-        let wave = 1.0 + 0.045 * (time.elapsed_secs() * 6.0 - body_index as f32 * 0.85).sin();
-        let scale_factor = taper * wave;
-
-        // Color gradient: t = 0.0 at the segment closest to the head,
-        // t = 1.0 at the tail. Single-segment body collapses to t = 0.0.
-        // The exponent biases the curve so brightness change-per-segment is
-        // small near the head and large near the tail — perceptually clearer
-        // than a linear ramp on long snakes.
-        let t_linear = if body_count <= 1 {
-            0.0
+        let (taper, color) =
+            body_segment_look(body_index, body_count, &theme, accessibility.high_contrast);
+        // Breathing wave traveling down the body (held still with reduced
+        // motion).
+        let wave = if accessibility.reduce_motion {
+            1.0
         } else {
-            body_index as f32 / (body_count - 1) as f32
+            1.0 + 0.045 * (time.elapsed_secs() * 6.0 - body_index as f32 * 0.85).sin()
         };
-        let t = t_linear.powf(BODY_GRADIENT_EXPONENT);
-        let color = lerp_color(BODY_COLOR_NEAR_HEAD, BODY_COLOR_NEAR_TAIL, t);
+        let scale_factor = taper * wave;
 
         let Ok((mut transform, mut fill)) = segments.get_mut(entity) else {
             continue;
@@ -535,3 +642,123 @@ fn style_snake_body(time: Res<Time>, game_state: Res<GameState>, mut segments: B
         }
     }
 }
+
+/// One point of the tube: a segment center with the width and color the
+/// pill there would have.
+#[derive(Clone, Copy)]
+struct TubePoint {
+    center: Vec2,
+    width: f32,
+    color: Color,
+}
+
+/// Draws the snake body as one continuous tube in the
+/// [`BodyStyle::Tube`] style, hiding the pill segments it replaces.
+///
+/// The tube runs through the interpolated segment centers, so it slides with
+/// the snake between ticks, and round caps on every piece turn each bend
+/// into a smooth elbow. Width and color are read back from the segments
+/// after [`style_snake_body`], so the tail taper, the breathing wave, the
+/// head→tail gradient and the grow-in animation all carry over unchanged.
+/// The head keeps its own shape and sits on the tube's front end.
+///
+/// A link that crosses a wrap-around edge is drawn from both of its ends;
+/// the arena mask hides whatever sticks out past the edge.
+#[allow(clippy::too_many_arguments)]
+fn draw_snake_tube(
+    mut painter: ShapePainter,
+    game_state: Res<GameState>,
+    settings: Res<ThemeSettings>,
+    theme: Res<Theme>,
+    accessibility: Res<AccessibilitySettings>,
+    arena: Res<Arena>,
+    points: TubePointQuery,
+    mut segments: Query<(&mut Visibility, Has<Burst>), With<SnakeSegment>>,
+) {
+    let tube = settings.body_style == BodyStyle::Tube;
+    // `None` for a part the death sequence has burst.
+    let mut tube_points: Vec<Option<TubePoint>> = game_state
+        .snake_segments
+        .iter()
+        .map(|&entity| {
+            points.get(entity).ok().map(|(transform, fill)| TubePoint {
+                center: transform.translation.truncate(),
+                width: CELL_SIZE * SEGMENT_SIZE_FACTOR * transform.scale.x,
+                color: fill.color,
+            })
+        })
+        .collect();
+    let in_tube = |index: usize| tube_points.get(index).is_some_and(Option::is_some);
+    for (index, &entity) in game_state.snake_segments.iter().enumerate() {
+        let Ok((mut visibility, burst)) = segments.get_mut(entity) else {
+            continue;
+        };
+        // A segment the burst left without neighbours has no tube to be part
+        // of, so it shows as its own pill.
+        let lone = !(index > 0 && in_tube(index - 1)) && !in_tube(index + 1);
+        visibility.set_if_neq(if burst || (tube && !lone) {
+            Visibility::Hidden
+        } else {
+            Visibility::Inherited
+        });
+    }
+    if !tube {
+        return;
+    }
+    // The head is drawn on its own; the tube just runs into it at the width
+    // and color of the first segment.
+    if let [Some(head), Some(neck), ..] = tube_points.as_mut_slice() {
+        head.width = neck.width;
+        head.color = neck.color;
+    }
+
+    let half = arena.world_size() / 2.0;
+    let wrapped = |delta: f32, half: f32| {
+        if !arena.walls && delta.abs() > half {
+            delta - delta.signum() * 2.0 * half
+        } else {
+            delta
+        }
+    };
+
+    painter.reset();
+    painter.cap = Cap::Round;
+    // High contrast traces the tube with the outline color underneath.
+    for (outline, z) in [(true, Z_SNAKE_SEGMENT - 0.1), (false, Z_SNAKE_SEGMENT)] {
+        if outline && !accessibility.high_contrast {
+            continue;
+        }
+        for link in tube_points.windows(2) {
+            let &[Some(from), Some(to)] = link else {
+                continue;
+            };
+            let raw = to.center - from.center;
+            let delta = Vec2::new(wrapped(raw.x, half.x), wrapped(raw.y, half.y));
+            let mut starts = vec![from.center];
+            if delta != raw {
+                starts.push(to.center - delta);
+            }
+            for start in starts {
+                for step in 0..TUBE_STEPS {
+                    let t0 = step as f32 / TUBE_STEPS as f32;
+                    let t1 = (step + 1) as f32 / TUBE_STEPS as f32;
+                    let width = from.width + (to.width - from.width) * t1;
+                    painter.thickness = if outline {
+                        width + 2.0 * OUTLINE_THICKNESS
+                    } else {
+                        width
+                    };
+                    painter.color = if outline {
+                        theme.outline()
+                    } else {
+                        lerp_color(from.color, to.color, t1)
+                    };
+                    painter.line(
+                        (start + delta * t0).extend(z),
+                        (start + delta * t1).extend(z),
+                    );
+                }
+            }
+        }
+    }
+}
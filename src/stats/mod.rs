@@ -0,0 +1,166 @@
+//! Run statistics plugin - keeps [`RunStats`] for the run being played:
+//! turns, straights, close calls, apples, the score's history and what
+//! ended the run. The end screens show them in their summary panel.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use snake_bevy::rules::Board;
+
+use crate::game::{
+    Arena, DeathCause, Direction, FoodEatenEvent, GameMode, GamePhase, GameSet, GameState,
+    INITIAL_SNAKE_POSITION, Position, RunClock, RunStats, SnakeHead, TurnEvent,
+};
+
+/// Plugin for per-run statistics.
+pub struct StatsPlugin;
+
+impl Plugin for StatsPlugin {
+    fn build(&self, app: &mut App) {
+        // After the move is resolved (growth, bites, crashes) and before
+        // the end screens read the stats.
+        app.add_systems(
+            Update,
+            track_run_stats.after(GameSet::Effects).before(GameSet::Ui),
+        );
+    }
+}
+
+/// What `track_run_stats` remembers between frames.
+#[derive(Default)]
+struct Tracker {
+    phase: GamePhase,
+    head: Option<Position>,
+    /// Moves since the last turn.
+    straight: usize,
+}
+
+/// Resets the stats when a run starts, then counts every move, turn and
+/// apple until it ends, and keeps the [`DeathCause`] if the snake dies.
+#[allow(clippy::too_many_arguments)]
+fn track_run_stats(
+    mut stats: ResMut<RunStats>,
+    mut tracker: Local<Tracker>,
+    game_state: Res<GameState>,
+    game_mode: Res<GameMode>,
+    arena: Res<Arena>,
+    run_clock: Res<RunClock>,
+    mut turns: MessageReader<TurnEvent>,
+    mut eaten: MessageReader<FoodEatenEvent>,
+    mut deaths: MessageReader<DeathCause>,
+    heads: Query<(&SnakeHead, &Position)>,
+    positions: Query<&Position>,
+) {
+    // Drain unconditionally so nothing from a finished run carries over.
+    let turned = turns.read().count();
+    let apples = eaten.read().count();
+    let death = deaths.read().last().copied();
+    let phase = game_state.phase;
+    let was_playing = std::mem::replace(&mut tracker.phase, phase) == GamePhase::Playing;
+    if !was_playing {
+        if phase != GamePhase::Playing {
+            return;
+        }
+        // A new run: its snake was spawned last frame and may already have
+        // made its first move.
+        *stats = RunStats {
+            score_history: vec![(Duration::ZERO, 0)],
+            ..default()
+        };
+        *tracker = Tracker {
+            phase,
+            head: Some(INITIAL_SNAKE_POSITION),
+            straight: 0,
+        };
+    }
+
+    stats.turns += turned;
+    stats.apples += apples;
+    if stats.score_history.last().map(|&(_, score)| score) != Some(game_state.score) {
+        stats
+            .score_history
+            .push((run_clock.elapsed, game_state.score));
+    }
+
+    stats.death = death.or(stats.death);
+    if phase == GamePhase::GameOver {
+        return;
+    }
+
+    let Ok((head, &position)) = heads.single() else {
+        return;
+    };
+    if tracker
+        .head
+        .replace(position)
+        .is_none_or(|last| last == position)
+    {
+        return;
+    }
+    tracker.straight = if turned > 0 { 1 } else { tracker.straight + 1 };
+    stats.longest_straight = stats.longest_straight.max(tracker.straight);
+    // Zen can't die, so nothing there is ever close.
+    if phase == GamePhase::Playing && *game_mode != GameMode::Zen {
+        let board = arena.board();
+        let cells: Vec<Position> = game_state
+            .snake_segments
+            .iter()
+            .filter_map(|&entity| positions.get(entity).ok().copied())
+            .collect();
+        let exits = safe_moves(&board, &cells, head.direction);
+        stats.closest_call = Some(stats.closest_call.map_or(exits, |fewest| fewest.min(exits)));
+    }
+}
+
+/// How many of the head's next moves (any way but straight back) neither
+/// leave a walled board nor run into the body. The tail counts as free: it
+/// moves out of the way.
+fn safe_moves(board: &Board, snake: &[Position], heading: Direction) -> usize {
+    let Some((&head, body)) = snake.split_first() else {
+        return 0;
+    };
+    let body = &body[..body.len().saturating_sub(1)];
+    Direction::ALL
+        .into_iter()
+        .filter(|&direction| direction != heading.opposite())
+        .filter_map(|direction| board.next_head(head, direction))
+        .filter(|cell| !body.contains(cell))
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn walled() -> Board {
+        Board {
+            walls: true,
+            ..Board::default()
+        }
+    }
+
+    fn cells(cells: &[(i32, i32)]) -> Vec<Position> {
+        cells.iter().map(|&(x, y)| Position { x, y }).collect()
+    }
+
+    #[test]
+    fn a_walled_corner_leaves_one_way_out() {
+        // Heading down into the bottom-left corner: down and left are walls,
+        // up is straight back.
+        let snake = cells(&[(0, 0), (0, 1), (0, 2)]);
+
+        assert_eq!(safe_moves(&walled(), &snake, Direction::Down), 1);
+        assert_eq!(safe_moves(&Board::default(), &snake, Direction::Down), 3);
+    }
+
+    #[test]
+    fn the_tail_counts_as_free() {
+        // The only way out, to the right, is the tail's cell: it moves away.
+        let snake = cells(&[(0, 0), (0, 1), (1, 1), (1, 0)]);
+        assert_eq!(safe_moves(&walled(), &snake, Direction::Down), 1);
+
+        // One segment longer, that cell is body and the snake is trapped.
+        let snake = cells(&[(0, 0), (0, 1), (1, 1), (1, 0), (2, 0)]);
+        assert_eq!(safe_moves(&walled(), &snake, Direction::Down), 0);
+    }
+}
@@ -0,0 +1,453 @@
+//! Integration tests: the real snake, food, UI and stats plugins running headless
+//! under `MinimalPlugins`, driven by key presses, `StartRequested` messages
+//! and hand-stepped time.
+//!
+//! Apples are still spawned by the game, but each test moves the one apple
+//! to the cell it wants eaten before the tick that reaches it, so no test
+//! depends on the food RNG. Every frame is also checked against the game's
+//! invariants (`debug::InvariantsPlugin`).
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy_vector_shapes::prelude::*;
+
+use crate::daily::DailyPlugin;
+use crate::debug::InvariantsPlugin;
+use crate::food::FoodPlugin;
+use crate::game::{
+    AccessibilitySettings, Arena, AudioSettings, Burst, DailyRecords, DeathCause, DeathHighlight,
+    DeathKind, EffectsSettings, FillBar, Food, GameOverUI, GamePhase, GamePlugin, GameState,
+    GameplaySettings, HighScore, HudText, InputBuffer, KeyBindings, LanguageSettings, Position,
+    RunStats, SaveRequested, StartRequested, ThemeSettings, TimeAttackRecords,
+    UnlockedAchievements, ZenSettings,
+};
+use crate::locale::LocalePlugin;
+use crate::rendering::RenderingPlugin;
+use crate::snake::SnakePlugin;
+use crate::stats::StatsPlugin;
+use crate::theme::ThemePlugin;
+use crate::ui::UiPlugin;
+
+/// Far from every path the tests steer, so the apple is only eaten where a
+/// test puts it.
+const OUT_OF_THE_WAY: Position = Position { x: 15, y: 15 };
+
+/// A headless app running the gameplay plugins.
+struct Harness {
+    app: App,
+}
+
+impl Harness {
+    fn new() -> Self {
+        Self::with_gameplay(GameplaySettings::default())
+    }
+
+    /// A harness whose runs are played with `gameplay` (arena size, walls,
+    /// speed).
+    fn with_gameplay(gameplay: GameplaySettings) -> Self {
+        Self::build(gameplay, false)
+    }
+
+    /// A harness that also runs the rendering plugin, for the death
+    /// sequence and the effects around it.
+    fn with_rendering() -> Self {
+        Self::build(GameplaySettings::default(), true)
+    }
+
+    fn build(gameplay: GameplaySettings, rendering: bool) -> Self {
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            bevy::scene::ScenePlugin,
+            Shape2dPlugin::default(),
+        ))
+        // Frames only advance the clock when a test asks for a move.
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::ZERO))
+        .init_resource::<ButtonInput<KeyCode>>()
+        // What `SettingsPlugin` would have loaded on a first launch. Without
+        // the plugin nothing reads `SaveRequested`, so no run writes the
+        // settings file.
+        .init_resource::<HighScore>()
+        .insert_resource(gameplay)
+        .init_resource::<TimeAttackRecords>()
+        .init_resource::<ZenSettings>()
+        .init_resource::<DailyRecords>()
+        .init_resource::<UnlockedAchievements>()
+        .init_resource::<AudioSettings>()
+        .init_resource::<ThemeSettings>()
+        .init_resource::<LanguageSettings>()
+        .init_resource::<AccessibilitySettings>()
+        .init_resource::<EffectsSettings>()
+        .init_resource::<KeyBindings>()
+        .add_plugins((
+            GamePlugin,
+            ThemePlugin,
+            LocalePlugin,
+            DailyPlugin,
+            SnakePlugin,
+            FoodPlugin,
+            UiPlugin,
+            StatsPlugin,
+            InvariantsPlugin,
+        ));
+        if rendering {
+            app.add_plugins(RenderingPlugin);
+        }
+        // Startup: camera, HUD, and the start menu.
+        app.update();
+        Harness { app }
+    }
+
+    /// Starts a run from the menu, as the START button does.
+    fn start(&mut self) {
+        self.app.world_mut().write_message(StartRequested);
+        self.app.update();
+        assert_eq!(self.state().phase, GamePhase::Playing);
+    }
+
+    /// Presses and releases `key` within one frame, without moving the
+    /// snake.
+    fn press(&mut self, key: KeyCode) {
+        self.keys().press(key);
+        self.app.update();
+        let mut keys = self.keys();
+        keys.release(key);
+        keys.clear();
+    }
+
+    fn keys(&mut self) -> Mut<'_, ButtonInput<KeyCode>> {
+        self.app.world_mut().resource_mut::<ButtonInput<KeyCode>>()
+    }
+
+    /// Runs one frame long enough for exactly one move.
+    fn tick(&mut self) {
+        let interval = self.app.world().resource::<Arena>().move_interval;
+        self.advance(interval);
+    }
+
+    /// Runs one frame `duration` long, in real and game time alike.
+    fn advance(&mut self, duration: Duration) {
+        self.app
+            .insert_resource(TimeUpdateStrategy::ManualDuration(duration));
+        self.app.update();
+        self.app
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::ZERO));
+    }
+
+    /// Moves the apple to `cell`.
+    fn place_food(&mut self, cell: Position) {
+        let world = self.app.world_mut();
+        let mut food = world.query_filtered::<&mut Position, With<Food>>();
+        *food.single_mut(world).expect("one apple") = cell;
+    }
+
+    /// Moves the apple to `cell`, then makes the move that eats it.
+    fn eat_at(&mut self, cell: Position) {
+        self.place_food(cell);
+        self.tick();
+    }
+
+    fn state(&self) -> &GameState {
+        self.app.world().resource::<GameState>()
+    }
+
+    /// The snake's cells, head first.
+    fn snake(&self) -> Vec<Position> {
+        let world = self.app.world();
+        self.state()
+            .snake_segments
+            .iter()
+            .map(|&entity| *world.get::<Position>(entity).expect("a positioned part"))
+            .collect()
+    }
+
+    /// The text of the HUD's `field`.
+    fn hud(&mut self, field: HudText) -> String {
+        let world = self.app.world_mut();
+        let mut texts = world.query::<(&HudText, &Text)>();
+        texts
+            .iter(world)
+            .find_map(|(shown, text)| (*shown == field).then(|| text.0.clone()))
+            .expect("a HUD text")
+    }
+
+    fn head(&self) -> Position {
+        self.snake()[0]
+    }
+}
+
+fn cell(x: i32, y: i32) -> Position {
+    Position { x, y }
+}
+
+#[test]
+fn leaving_an_edge_wraps_to_the_opposite_one() {
+    let mut game = Harness::new();
+    game.start();
+    game.place_food(OUT_OF_THE_WAY);
+    assert_eq!(game.head(), cell(3, 3));
+
+    game.press(KeyCode::ArrowDown);
+    for _ in 0..4 {
+        game.tick();
+    }
+    assert_eq!(game.head(), cell(3, 19));
+    assert_eq!(game.state().phase, GamePhase::Playing);
+}
+
+#[test]
+fn growth_fills_the_cell_the_tail_left() {
+    let mut game = Harness::new();
+    game.start();
+
+    game.eat_at(cell(4, 3));
+    assert_eq!(game.snake(), [cell(4, 3), cell(3, 3)]);
+
+    game.eat_at(cell(5, 3));
+    game.place_food(OUT_OF_THE_WAY);
+    assert_eq!(game.snake(), [cell(5, 3), cell(4, 3), cell(3, 3)]);
+    assert_eq!(game.state().score, 2);
+
+    // Without an apple the snake keeps its length.
+    game.tick();
+    assert_eq!(game.snake(), [cell(6, 3), cell(5, 3), cell(4, 3)]);
+}
+
+#[test]
+fn the_hud_follows_the_run() {
+    let mut game = Harness::new();
+    game.start();
+    game.eat_at(cell(4, 3));
+    game.eat_at(cell(5, 3));
+    game.place_food(OUT_OF_THE_WAY);
+
+    assert_eq!(game.hud(HudText::Score), "Score: 2");
+    assert_eq!(game.hud(HudText::Length), "Length: 3");
+    assert_eq!(game.hud(HudText::Speed), "NORMAL");
+    let world = game.app.world_mut();
+    let mut bars = world.query::<(&FillBar, &Node)>();
+    let fill = bars
+        .iter(world)
+        .find_map(|(part, node)| (*part == FillBar::Fill).then_some(node.width));
+    // 3 of the 400 cells.
+    let Some(Val::Percent(percent)) = fill else {
+        panic!("fill bar width: {fill:?}");
+    };
+    assert!((percent - 0.75).abs() < 1e-4);
+}
+
+#[test]
+fn biting_the_body_ends_the_run() {
+    let mut game = Harness::new();
+    game.start();
+    for x in 4..8 {
+        game.eat_at(cell(x, 3));
+    }
+    game.place_food(OUT_OF_THE_WAY);
+    assert_eq!(game.snake().len(), 5);
+
+    // A tight U-turn: up, left, then down into the body.
+    for key in [KeyCode::ArrowUp, KeyCode::ArrowLeft, KeyCode::ArrowDown] {
+        game.press(key);
+        game.tick();
+    }
+    assert_eq!(game.head(), cell(6, 3));
+    assert_eq!(game.state().phase, GamePhase::GameOver);
+}
+
+#[test]
+fn a_new_record_is_kept_and_saved() {
+    let mut game = Harness::new();
+    game.start();
+    for x in 4..8 {
+        game.eat_at(cell(x, 3));
+    }
+    game.place_food(OUT_OF_THE_WAY);
+    for key in [KeyCode::ArrowUp, KeyCode::ArrowLeft, KeyCode::ArrowDown] {
+        game.press(key);
+        game.tick();
+    }
+    assert_eq!(game.state().phase, GamePhase::GameOver);
+
+    let world = game.app.world();
+    assert_eq!(world.resource::<HighScore>().score, 4);
+    assert!(!world.resource::<Messages<SaveRequested>>().is_empty());
+}
+
+#[test]
+fn the_run_stats_follow_the_run() {
+    let mut game = Harness::new();
+    game.start();
+    for x in 4..8 {
+        game.eat_at(cell(x, 3));
+    }
+    game.place_food(OUT_OF_THE_WAY);
+    // The same U-turn into the body as above, after four straight moves.
+    for key in [KeyCode::ArrowUp, KeyCode::ArrowLeft, KeyCode::ArrowDown] {
+        game.press(key);
+        game.tick();
+    }
+
+    let stats = game.app.world().resource::<RunStats>();
+    assert_eq!(stats.turns, 3);
+    assert_eq!(stats.longest_straight, 4);
+    assert_eq!(stats.apples, 4);
+    // Heading left beside the body, only straight on or up was safe.
+    assert_eq!(stats.closest_call, Some(2));
+    assert_eq!(
+        stats
+            .score_history
+            .iter()
+            .map(|&(_, score)| score)
+            .collect::<Vec<_>>(),
+        [0, 1, 2, 3, 4]
+    );
+    // The head bit the tail, which had just slid into (6, 3) too.
+    assert_eq!(
+        stats.death,
+        Some(DeathCause {
+            kind: DeathKind::SelfBite { segment: 4 },
+            cell: cell(6, 3),
+        })
+    );
+}
+
+#[test]
+fn running_into_a_wall_ends_the_run() {
+    let mut game = Harness::with_gameplay(GameplaySettings {
+        walls: true,
+        ..default()
+    });
+    game.start();
+    game.place_food(OUT_OF_THE_WAY);
+
+    game.press(KeyCode::ArrowDown);
+    for _ in 0..3 {
+        game.tick();
+    }
+    assert_eq!(game.head(), cell(3, 0));
+    assert_eq!(game.state().phase, GamePhase::Playing);
+
+    game.tick();
+    assert_eq!(game.head(), cell(3, 0));
+    assert_eq!(game.state().phase, GamePhase::GameOver);
+    assert_eq!(
+        game.app.world().resource::<RunStats>().death,
+        Some(DeathCause {
+            kind: DeathKind::Wall,
+            cell: cell(3, 0),
+        })
+    );
+}
+
+#[test]
+fn the_death_cell_keeps_its_part_under_the_end_screen() {
+    let mut game = Harness::with_rendering();
+    game.start();
+    for x in 4..8 {
+        game.eat_at(cell(x, 3));
+    }
+    game.place_food(OUT_OF_THE_WAY);
+    // The U-turn of `biting_the_body_ends_the_run`: the head bites the tail
+    // at (6, 3).
+    for key in [KeyCode::ArrowUp, KeyCode::ArrowLeft, KeyCode::ArrowDown] {
+        game.press(key);
+        game.tick();
+    }
+    assert_eq!(game.state().phase, GamePhase::GameOver);
+
+    // Let the death sequence play out until the end screen is up.
+    let world = game.app.world_mut();
+    let mut end_screens = world.query_filtered::<(), With<GameOverUI>>();
+    for _ in 0..50 {
+        if end_screens.iter(game.app.world()).next().is_some() {
+            break;
+        }
+        game.advance(Duration::from_millis(100));
+    }
+    assert!(end_screens.iter(game.app.world()).next().is_some());
+
+    let world = game.app.world_mut();
+    let mut glows = world.query_filtered::<&Transform, With<DeathHighlight>>();
+    let glow = glows
+        .iter(world)
+        .next()
+        .expect("a death highlight")
+        .translation
+        .truncate();
+    let segments = game.state().snake_segments.clone();
+    let world = game.app.world();
+    let standing: Vec<Position> = segments
+        .iter()
+        .filter(|&&entity| world.get::<Burst>(entity).is_none())
+        .map(|&entity| *world.get::<Position>(entity).expect("a positioned part"))
+        .collect();
+    assert_eq!(standing, [cell(6, 3)]);
+    assert_eq!(world.resource::<Arena>().to_world(cell(6, 3)), glow);
+}
+
+#[test]
+fn reversing_into_the_body_is_ignored() {
+    let mut game = Harness::new();
+    game.start();
+    game.place_food(OUT_OF_THE_WAY);
+
+    // Straight back while heading right: never even queued.
+    game.press(KeyCode::ArrowLeft);
+    assert_eq!(
+        game.app.world().resource::<InputBuffer>().queued().count(),
+        0
+    );
+    game.tick();
+    assert_eq!(game.head(), cell(4, 3));
+
+    // Down reverses the Up queued before it, so it is dropped as well.
+    game.press(KeyCode::ArrowUp);
+    game.press(KeyCode::ArrowDown);
+    game.tick();
+    game.tick();
+    assert_eq!(game.head(), cell(4, 5));
+    assert_eq!(game.state().phase, GamePhase::Playing);
+}
+
+#[test]
+fn filling_the_board_wins() {
+    let mut game = Harness::with_gameplay(GameplaySettings {
+        arena_size: 4,
+        ..default()
+    });
+    game.start();
+
+    // From the start cell (3, 3), snake down and up the columns, eating on
+    // each of the other fifteen cells of the 4×4 board.
+    let path = [
+        (Some(KeyCode::ArrowDown), cell(3, 2)),
+        (None, cell(3, 1)),
+        (None, cell(3, 0)),
+        (Some(KeyCode::ArrowLeft), cell(2, 0)),
+        (Some(KeyCode::ArrowUp), cell(2, 1)),
+        (None, cell(2, 2)),
+        (None, cell(2, 3)),
+        (Some(KeyCode::ArrowLeft), cell(1, 3)),
+        (Some(KeyCode::ArrowDown), cell(1, 2)),
+        (None, cell(1, 1)),
+        (None, cell(1, 0)),
+        (Some(KeyCode::ArrowLeft), cell(0, 0)),
+        (Some(KeyCode::ArrowUp), cell(0, 1)),
+        (None, cell(0, 2)),
+        (None, cell(0, 3)),
+    ];
+    for (turn, next) in path {
+        assert_eq!(game.state().phase, GamePhase::Playing);
+        if let Some(key) = turn {
+            game.press(key);
+        }
+        game.eat_at(next);
+    }
+    assert_eq!(game.state().phase, GamePhase::Won);
+    assert_eq!(game.state().score, 15);
+    assert_eq!(game.snake().len(), 16);
+}
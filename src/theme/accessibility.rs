@@ -0,0 +1,100 @@
+//! Accessibility adjustments layered over a theme: color-vision-safe
+//! palettes for the snake and the apple, and a high-contrast mode.
+//!
+//! The safe palettes are drawn from the Okabe-Ito set, pairing colors that
+//! stay distinct under the given color-vision deficiency. Head and apple
+//! colors keep components above 1.0 so they still bloom like the themes'.
+
+use bevy::prelude::*;
+
+use super::Theme;
+use crate::game::{AccessibilitySettings, ColorVision};
+
+/// The snake and apple colors of one color-vision-safe palette.
+struct SafePalette {
+    snake_head: Color,
+    body_near_head: Color,
+    body_near_tail: Color,
+    food: Color,
+    particles: [Color; 3],
+}
+
+/// Sky-blue snake shared by the two red-green palettes.
+const BLUE_SNAKE_HEAD: Color = Color::srgba(0.5, 1.1, 1.8, 1.0);
+const BLUE_BODY_NEAR_HEAD: Color = Color::srgba(0.34, 0.71, 0.91, 1.0);
+const BLUE_BODY_NEAR_TAIL: Color = Color::srgba(0.05, 0.18, 0.32, 1.0);
+
+fn safe_palette(vision: ColorVision) -> Option<SafePalette> {
+    match vision {
+        ColorVision::Standard => None,
+        ColorVision::Deuteranopia => Some(SafePalette {
+            snake_head: BLUE_SNAKE_HEAD,
+            body_near_head: BLUE_BODY_NEAR_HEAD,
+            body_near_tail: BLUE_BODY_NEAR_TAIL,
+            // Vermillion / orange.
+            food: Color::srgba(2.2, 0.9, 0.0, 1.0),
+            particles: [
+                Color::srgba(2.2, 0.9, 0.0, 1.0),
+                Color::srgba(2.0, 1.4, 0.2, 1.0),
+                Color::srgba(1.6, 0.7, 0.1, 1.0),
+            ],
+        }),
+        ColorVision::Protanopia => Some(SafePalette {
+            snake_head: BLUE_SNAKE_HEAD,
+            body_near_head: BLUE_BODY_NEAR_HEAD,
+            body_near_tail: BLUE_BODY_NEAR_TAIL,
+            // Yellow-orange: reds look dark to protanopes, yellows don't.
+            food: Color::srgba(2.0, 1.6, 0.0, 1.0),
+            particles: [
+                Color::srgba(2.0, 1.6, 0.0, 1.0),
+                Color::srgba(2.2, 1.9, 0.5, 1.0),
+                Color::srgba(1.8, 1.2, 0.1, 1.0),
+            ],
+        }),
+        ColorVision::Tritanopia => Some(SafePalette {
+            snake_head: Color::srgba(0.3, 1.5, 1.1, 1.0),
+            body_near_head: Color::srgba(0.0, 0.75, 0.55, 1.0),
+            body_near_tail: Color::srgba(0.0, 0.22, 0.16, 1.0),
+            // Reddish pink: tritanopes keep the red-green axis.
+            food: Color::srgba(2.4, 0.3, 0.6, 1.0),
+            particles: [
+                Color::srgba(2.4, 0.3, 0.6, 1.0),
+                Color::srgba(2.0, 0.6, 0.9, 1.0),
+                Color::srgba(1.6, 0.2, 0.4, 1.0),
+            ],
+        }),
+    }
+}
+
+impl Theme {
+    /// This theme with the active accessibility options applied.
+    pub fn adapted(&self, accessibility: &AccessibilitySettings) -> Theme {
+        let mut theme = self.clone();
+        if let Some(palette) = safe_palette(accessibility.color_vision) {
+            theme.snake_head = palette.snake_head;
+            theme.snake_segment = palette.body_near_head;
+            theme.body_near_head = palette.body_near_head;
+            theme.body_near_tail = palette.body_near_tail;
+            theme.food = palette.food;
+            theme.eaten_ring = palette.food.with_alpha(0.5);
+            theme.particles = palette.particles.to_vec();
+        }
+        if accessibility.high_contrast {
+            // The checkerboard goes so nothing competes with the outlines;
+            // `style_snake_body` stops the tail fade.
+            theme.arena_alt = theme.arena;
+            theme.arena_border = theme.outline();
+        }
+        theme
+    }
+
+    /// Black or white, whichever stands out against the arena — used for
+    /// the high-contrast outlines and the apple's shape marker.
+    pub fn outline(&self) -> Color {
+        if self.arena.luminance() > 0.5 {
+            Color::BLACK
+        } else {
+            Color::WHITE
+        }
+    }
+}
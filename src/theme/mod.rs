@@ -0,0 +1,322 @@
+//! Theme plugin - owns the active [`Theme`]: the palette every spawn and
+//! styling system reads instead of hard-coded color constants.
+//!
+//! Themes are RON files. The built-in ones live in `themes/` and are
+//! embedded at compile time; native builds also load every `*.ron` file
+//! found in a `themes/` directory under the working directory at startup
+//! (a file whose `name` matches a built-in replaces it). The active theme
+//! is the chosen one with the accessibility options applied on top (see
+//! [`accessibility`]). Switching themes restyles live entities in place —
+//! see [`restyle_world`].
+
+mod accessibility;
+
+use bevy::prelude::*;
+use bevy_vector_shapes::prelude::*;
+use serde::{Deserialize, Deserializer};
+
+use crate::game::{AccessibilitySettings, GameSet, ThemeColor, ThemeSettings};
+
+/// The built-in themes, in picker order. The first is the default.
+const BUILT_IN_THEMES: [&str; 4] = [
+    include_str!("../../themes/neon.ron"),
+    include_str!("../../themes/retro_lcd.ron"),
+    include_str!("../../themes/pastel.ron"),
+    include_str!("../../themes/monochrome.ron"),
+];
+
+/// Directory scanned for extra theme files on native builds.
+#[cfg(not(target_arch = "wasm32"))]
+const CUSTOM_THEME_DIR: &str = "themes";
+
+/// A complete color palette. Colors are written in theme files as sRGB
+/// `(r, g, b, a)` tuples; components above 1.0 glow through the bloom pass.
+#[derive(Resource, Debug, Clone, Deserialize)]
+pub struct Theme {
+    /// Shown in the theme picker and stored in [`ThemeSettings`].
+    pub name: String,
+
+    // Playfield
+    #[serde(deserialize_with = "srgba")]
+    pub background: Color,
+    #[serde(deserialize_with = "srgba")]
+    pub arena: Color,
+    /// Checkerboard tint for alternating cells — barely off `arena` so the
+    /// grid reads without competing with the pieces.
+    #[serde(deserialize_with = "srgba")]
+    pub arena_alt: Color,
+    #[serde(deserialize_with = "srgba")]
+    pub arena_border: Color,
+
+    // Snake
+    #[serde(deserialize_with = "srgba")]
+    pub snake_head: Color,
+    /// A body segment's color while it grows in, before the gradient
+    /// takes over.
+    #[serde(deserialize_with = "srgba")]
+    pub snake_segment: Color,
+    /// Ends of the body gradient applied by `style_snake_body`.
+    #[serde(deserialize_with = "srgba")]
+    pub body_near_head: Color,
+    #[serde(deserialize_with = "srgba")]
+    pub body_near_tail: Color,
+    #[serde(deserialize_with = "srgba")]
+    pub eye: Color,
+    #[serde(deserialize_with = "srgba")]
+    pub pupil: Color,
+    #[serde(deserialize_with = "srgba")]
+    pub tongue: Color,
+
+    // Apple
+    #[serde(deserialize_with = "srgba")]
+    pub food: Color,
+    #[serde(deserialize_with = "srgba")]
+    pub apple_highlight: Color,
+    #[serde(deserialize_with = "srgba")]
+    pub apple_stem: Color,
+    #[serde(deserialize_with = "srgba")]
+    pub apple_leaf: Color,
+
+    // Eating effects
+    #[serde(deserialize_with = "srgba")]
+    pub food_eaten: Color,
+    #[serde(deserialize_with = "srgba")]
+    pub eaten_ring: Color,
+    #[serde(deserialize_with = "srgba")]
+    pub score_popup: Color,
+    /// Juice-droplet palette for the eating burst; each droplet picks one.
+    #[serde(deserialize_with = "srgba_list")]
+    pub particles: Vec<Color>,
+
+    // UI
+    #[serde(deserialize_with = "srgba")]
+    pub title: Color,
+    #[serde(deserialize_with = "srgba")]
+    pub text: Color,
+    #[serde(deserialize_with = "srgba")]
+    pub hint: Color,
+    #[serde(deserialize_with = "srgba")]
+    pub dim: Color,
+    #[serde(deserialize_with = "srgba")]
+    pub locked: Color,
+    #[serde(deserialize_with = "srgba")]
+    pub danger: Color,
+    /// Button borders.
+    #[serde(deserialize_with = "srgba")]
+    pub accent: Color,
+    #[serde(deserialize_with = "srgba")]
+    pub highlight: Color,
+    #[serde(deserialize_with = "srgba")]
+    pub record: Color,
+    #[serde(deserialize_with = "srgba")]
+    pub button: Color,
+    #[serde(deserialize_with = "srgba")]
+    pub button_hovered: Color,
+    #[serde(deserialize_with = "srgba")]
+    pub button_pressed: Color,
+    /// Resting background of the selected choice in a selector row.
+    #[serde(deserialize_with = "srgba")]
+    pub button_selected: Color,
+    #[serde(deserialize_with = "srgba")]
+    pub toast_bg: Color,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme::parse(BUILT_IN_THEMES[0]).expect("built-in theme must parse")
+    }
+}
+
+impl Theme {
+    /// Parses a theme from RON source.
+    pub fn parse(source: &str) -> Result<Theme, ron::error::SpannedError> {
+        ron::from_str(source)
+    }
+
+    /// The color an entity tagged with `slot` should be painted.
+    pub fn color(&self, slot: ThemeColor) -> Color {
+        match slot {
+            ThemeColor::Background => self.background,
+            ThemeColor::Arena => self.arena,
+            ThemeColor::ArenaAlt => self.arena_alt,
+            ThemeColor::ArenaBorder => self.arena_border,
+            ThemeColor::SnakeHead => self.snake_head,
+            ThemeColor::Eye => self.eye,
+            ThemeColor::Pupil => self.pupil,
+            ThemeColor::Tongue => self.tongue,
+            ThemeColor::Food => self.food,
+            ThemeColor::AppleHighlight => self.apple_highlight,
+            ThemeColor::AppleStem => self.apple_stem,
+            ThemeColor::AppleLeaf => self.apple_leaf,
+            ThemeColor::Text => self.text,
+            ThemeColor::Record => self.record,
+            ThemeColor::Outline => self.outline(),
+        }
+    }
+}
+
+/// Deserializes an `(r, g, b, a)` sRGB tuple into a [`Color`].
+fn srgba<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Color, D::Error> {
+    let (r, g, b, a) = <(f32, f32, f32, f32)>::deserialize(deserializer)?;
+    Ok(Color::srgba(r, g, b, a))
+}
+
+/// Deserializes a list of `(r, g, b, a)` sRGB tuples.
+fn srgba_list<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<Color>, D::Error> {
+    let tuples = Vec::<(f32, f32, f32, f32)>::deserialize(deserializer)?;
+    Ok(tuples
+        .into_iter()
+        .map(|(r, g, b, a)| Color::srgba(r, g, b, a))
+        .collect())
+}
+
+/// Every theme the player can pick, in picker order: the built-ins, then
+/// any custom files.
+#[derive(Resource)]
+pub struct ThemeLibrary {
+    pub themes: Vec<Theme>,
+}
+
+impl ThemeLibrary {
+    /// Parses the built-ins and loads custom theme files.
+    fn load() -> Self {
+        let mut themes: Vec<Theme> = BUILT_IN_THEMES
+            .iter()
+            .map(|source| Theme::parse(source).expect("built-in theme must parse"))
+            .collect();
+        for custom in load_custom_themes() {
+            match themes.iter_mut().find(|theme| theme.name == custom.name) {
+                Some(existing) => *existing = custom,
+                None => themes.push(custom),
+            }
+        }
+        ThemeLibrary { themes }
+    }
+
+    /// The theme called `name`, if there is one.
+    pub fn get(&self, name: &str) -> Option<&Theme> {
+        self.themes.iter().find(|theme| theme.name == name)
+    }
+}
+
+/// Reads every `*.ron` file in [`CUSTOM_THEME_DIR`], in file-name order.
+/// Files that fail to parse are skipped with a warning.
+#[cfg(not(target_arch = "wasm32"))]
+fn load_custom_themes() -> Vec<Theme> {
+    let Ok(entries) = std::fs::read_dir(CUSTOM_THEME_DIR) else {
+        return Vec::new();
+    };
+    let mut paths: Vec<_> = entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "ron"))
+        .collect();
+    paths.sort();
+    paths
+        .into_iter()
+        .filter_map(|path| {
+            let source = std::fs::read_to_string(&path).ok()?;
+            Theme::parse(&source)
+                .inspect_err(|err| warn!("Skipping theme {}: {err}", path.display()))
+                .ok()
+        })
+        .collect()
+}
+
+/// The web build has no file system to read themes from.
+#[cfg(target_arch = "wasm32")]
+fn load_custom_themes() -> Vec<Theme> {
+    Vec::new()
+}
+
+/// Plugin for the theme library, the active theme, and live restyling.
+pub struct ThemePlugin;
+
+impl Plugin for ThemePlugin {
+    fn build(&self, app: &mut App) {
+        let library = ThemeLibrary::load();
+        // `SettingsPlugin` has already loaded the saved choices, so the very
+        // first frame (and every `Startup` spawn) uses the right theme.
+        let world = app.world();
+        let theme = world
+            .get_resource::<ThemeSettings>()
+            .and_then(|settings| library.get(&settings.theme))
+            .cloned()
+            .unwrap_or_default();
+        let theme = match world.get_resource::<AccessibilitySettings>() {
+            Some(accessibility) => theme.adapted(accessibility),
+            None => theme,
+        };
+        app.insert_resource(ClearColor(theme.background))
+            .insert_resource(theme)
+            .insert_resource(library)
+            .add_systems(
+                Update,
+                (refresh_theme, restyle_world).chain().in_set(GameSet::Ui),
+            );
+    }
+}
+
+/// Rebuilds the active [`Theme`] when the chosen theme or the accessibility
+/// options change (both are edited on the settings screen).
+fn refresh_theme(
+    library: Res<ThemeLibrary>,
+    settings: Res<ThemeSettings>,
+    accessibility: Res<AccessibilitySettings>,
+    mut theme: ResMut<Theme>,
+) {
+    // The plugin already built the first theme from the loaded settings.
+    if theme.is_added() || !(settings.is_changed() || accessibility.is_changed()) {
+        return;
+    }
+    let chosen = library.get(&settings.theme).cloned().unwrap_or_default();
+    *theme = chosen.adapted(&accessibility);
+}
+
+/// Repaints every long-lived entity tagged with [`ThemeColor`] when the
+/// theme changes.
+///
+/// Everything else picks the new theme up by itself: the snake body
+/// gradient and button backgrounds are restyled every frame, menus are
+/// rebuilt, and transient effects are spawned with the current colors.
+fn restyle_world(
+    theme: Res<Theme>,
+    mut clear_color: ResMut<ClearColor>,
+    mut shapes: Query<(&ThemeColor, &mut ShapeFill)>,
+    mut sprites: Query<(&ThemeColor, &mut Sprite)>,
+    mut texts: Query<(&ThemeColor, &mut TextColor)>,
+) {
+    if !theme.is_changed() || theme.is_added() {
+        return;
+    }
+    clear_color.0 = theme.background;
+    for (&slot, mut fill) in &mut shapes {
+        fill.color = theme.color(slot);
+    }
+    for (&slot, mut sprite) in &mut sprites {
+        sprite.color = theme.color(slot);
+    }
+    for (&slot, mut text_color) in &mut texts {
+        text_color.0 = theme.color(slot);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn built_in_themes_parse() {
+        let mut names = Vec::new();
+        for (index, source) in BUILT_IN_THEMES.iter().enumerate() {
+            let theme = Theme::parse(source)
+                .unwrap_or_else(|error| panic!("built-in theme {index}: {error}"));
+            names.push(theme.name);
+        }
+        // The picker and the settings file tell themes apart by name.
+        let count = names.len();
+        names.sort();
+        names.dedup();
+        assert_eq!(names.len(), count, "two built-in themes share a name");
+    }
+}
@@ -3,57 +3,95 @@
 //! Screens are declared with Bevy's BSN scene notation (`bsn!`): each screen
 //! is a plain function returning `impl Scene`, composed from the `overlay`
 //! and `label` building blocks below and spawned via `Commands::spawn_scene`.
+//! The settings screen lives in [`settings`].
+//!
+//! Every piece of text comes from the active [`Locale`]; scene functions
+//! take it alongside the [`Theme`].
+
+mod settings;
+
+use std::time::Duration;
 
-use bevy::camera::Hdr;
+use bevy::camera::{Hdr, ScalingMode};
+use bevy::ecs::system::SystemParam;
 use bevy::picking::hover::Hovered;
 use bevy::post_process::bloom::Bloom;
 use bevy::prelude::*;
-use bevy::settings::SaveSettings;
 use bevy::text::FontWeight;
 use bevy::ui::Pressed;
 use bevy::ui_widgets::{Activate, Button as WidgetButton};
+use bevy::window::{MonitorSelection, PrimaryWindow, WindowMode};
 
 use bevy_vector_shapes::prelude::*;
 
-use crate::food::spawn_food;
+use crate::achievements::ACHIEVEMENTS;
+use crate::daily;
+use crate::food::{FoodRng, spawn_food};
 use crate::game::{
-    ARENA_BORDER_COLOR, ARENA_COLOR, ARENA_COLOR_ALT, ARENA_HEIGHT, ARENA_WIDTH, CELL_SIZE,
-    CameraShake, Food, GameOverUI, GamePhase, GameSet, GameState, HighScore,
-    INITIAL_SNAKE_POSITION, InputBuffer, MenuUI, Particle, PulseEffect, ScorePopup, ScoreText,
-    SnakeHead, SnakeSegment, StartRequested, WinUI, Z_BACKGROUND,
+    AccessibilitySettings, Arena, ArenaTile, CELL_SIZE, CameraShake, ChoiceButton,
+    DEFAULT_VIEW_SIDE, DailyRecords, DailyRun, DeathCause, DeathHighlight, DeathKind,
+    DeathSequence, EffectsSettings, FadeEffect, FillBar, Food, GameMode, GameOverUI, GamePhase,
+    GameSet, GameState, GameplaySettings, HUD_HEIGHT, HighScore, HudText, HudUI,
+    INITIAL_SNAKE_POSITION, InputBuffer, MenuNavigate, MenuScreen, MenuUI, OverlayFadeIn, Particle,
+    Position, PulseEffect, ReturnToMenu, RunClock, RunStats, RunSummaryPanel, SaveRequested,
+    ScorePopup, SelectMode, SetZenBite, SettingsPage, SnakeHead, SnakeSegment, SparkBar, Speed,
+    StartRequested, ThemeColor, TimeAttackRecords, TimeUpUI, TimerText, ToggleRunSummary,
+    UnlockedAchievements, WINDOW_PADDING, WinUI, Z_ARENA_MASK, Z_BACKGROUND, ZenBite, ZenSettings,
 };
+use crate::locale::Locale;
+use crate::rendering::BLOOM_INTENSITY;
 use crate::snake::spawn_snake_head;
+use crate::theme::Theme;
+use settings::{
+    Rebinding, SettingsCursor, SettingsView, apply_setting_adjustments, capture_rebind,
+    navigate_settings, settings_screen,
+};
+
+/// Scrim alpha of the end screens.
+const END_SCREEN_SCRIM: f32 = 0.82;
+/// How long the game-over screen takes to fade in after the death sequence.
+const GAME_OVER_FADE_SECONDS: f32 = 0.35;
+/// Columns of the run summary's score sparkline.
+const SPARKLINE_COLUMNS: u32 = 24;
 
-// Shared UI palette.
-const TITLE_GREEN: Color = Color::srgba(0.3, 1.0, 0.3, 1.0);
-const HINT_GRAY: Color = Color::srgba(0.8, 0.8, 0.8, 1.0);
-const GAME_OVER_RED: Color = Color::srgba(1.0, 0.3, 0.3, 1.0);
-// Start instructions — same hue as the title, slightly dimmer so the title
-// stays the dominant element on the menu.
-const START_GREEN: Color = Color::srgba(0.4, 0.85, 0.4, 1.0);
-const MENU_GOLD: Color = Color::srgba(0.9, 0.8, 0.3, 1.0);
-const RECORD_GOLD: Color = Color::srgba(1.0, 0.85, 0.3, 1.0);
-const BEST_GRAY: Color = Color::srgba(0.6, 0.6, 0.6, 1.0);
-// Action-button backgrounds: same green family as the snake, stepping
-// brighter on hover and brighter again while pressed.
-const BUTTON_BG: Color = Color::srgba(0.10, 0.32, 0.10, 1.0);
-const BUTTON_BG_HOVERED: Color = Color::srgba(0.14, 0.45, 0.14, 1.0);
-const BUTTON_BG_PRESSED: Color = Color::srgba(0.18, 0.58, 0.18, 1.0);
+/// Converts a size in pixels of the default window to `vmin` units, which
+/// track the view (`DEFAULT_VIEW_SIDE`) whatever the window's size.
+fn view_vmin(pixels: f32) -> f32 {
+    pixels / DEFAULT_VIEW_SIDE * 100.0
+}
 
 /// Plugin for UI and game flow systems.
 pub struct UiPlugin;
 
 impl Plugin for UiPlugin {
     fn build(&self, app: &mut App) {
+        app.init_resource::<SettingsCursor>()
+            .init_resource::<Rebinding>()
+            .add_systems(Update, toggle_fullscreen);
         app.add_systems(Startup, setup_system).add_systems(
             Update,
             (
+                // Before `navigate_menu`, so a key the settings screen
+                // consumes (e.g. ESC cancelling a rebind) never also leaves
+                // the screen.
+                (capture_rebind, navigate_settings, apply_setting_adjustments).chain(),
+                navigate_menu,
+                select_mode,
+                select_zen_bite,
+                (preview_arena, spawn_arena, fit_camera_to_arena).chain(),
+                show_menu_screen,
                 start_game_from_menu,
                 restart_game,
-                update_score_text,
+                return_to_menu,
+                advance_run_clock,
+                time_attack_countdown,
+                update_hud_text,
+                update_timer_text,
                 update_score_visibility,
                 spawn_game_over_screen_system,
                 spawn_win_screen_system,
+                spawn_time_up_screen_system,
+                toggle_run_summary,
                 // Must run after the two spawn systems: the end screens
                 // compare the final score against the *previous* record to
                 // decide whether to show "NEW HIGH SCORE!".
@@ -61,6 +99,9 @@ impl Plugin for UiPlugin {
                 // Cosmetic hover/pressed feedback — no ordering constraints,
                 // it just lives at the end of the UI chain.
                 button_feedback,
+                // After the feedback, which would reset the fading buttons
+                // to full opacity.
+                fade_in_overlays,
             )
                 .chain()
                 .in_set(GameSet::Ui),
@@ -70,21 +111,79 @@ impl Plugin for UiPlugin {
 
 // Type alias for querying snake entities
 type SnakeEntityQuery<'w, 's> = Query<'w, 's, Entity, Or<(With<SnakeSegment>, With<SnakeHead>)>>;
-// Every kind of transient eating effect, for restart cleanup.
-type EffectEntityQuery<'w, 's> =
-    Query<'w, 's, Entity, Or<(With<PulseEffect>, With<Particle>, With<ScorePopup>)>>;
+// Every kind of transient effect (eating, the death highlight), for restart
+// cleanup.
+type EffectEntityQuery<'w, 's> = Query<
+    'w,
+    's,
+    Entity,
+    Or<(
+        With<PulseEffect>,
+        With<FadeEffect>,
+        With<Particle>,
+        With<ScorePopup>,
+        With<DeathHighlight>,
+    )>,
+>;
+// Whichever end-screen overlay is currently visible.
+type EndScreenQuery<'w, 's> =
+    Query<'w, 's, Entity, Or<(With<GameOverUI>, With<WinUI>, With<TimeUpUI>)>>;
+
+/// Read access to every stored record, for the screens that show them.
+#[derive(SystemParam)]
+struct Records<'w> {
+    high_score: Res<'w, HighScore>,
+    time_attack: Res<'w, TimeAttackRecords>,
+    daily: Res<'w, DailyRecords>,
+}
+
+impl Records<'_> {
+    /// The stored record that runs in `mode` compete against (Zen keeps
+    /// none; Daily shows its best day).
+    fn best(&self, mode: GameMode) -> usize {
+        match mode {
+            GameMode::Classic => self.high_score.score,
+            GameMode::TimeAttack { limit } => self.time_attack.best(limit),
+            GameMode::Zen => 0,
+            GameMode::Daily => self.daily.best(),
+        }
+    }
+}
+
+/// What the end screens' summary reports about the run that just ended.
+#[derive(SystemParam)]
+struct RunReport<'w> {
+    stats: Res<'w, RunStats>,
+    clock: Res<'w, RunClock>,
+}
 
-/// Initial setup system - camera, arena, score text, start menu.
+/// Everything `begin_new_game` resets, bundled so the start and restart
+/// systems stay readable.
+#[derive(SystemParam)]
+struct RunReset<'w> {
+    game_state: ResMut<'w, GameState>,
+    camera_shake: ResMut<'w, CameraShake>,
+    run_clock: ResMut<'w, RunClock>,
+    food_rng: ResMut<'w, FoodRng>,
+    arena: ResMut<'w, Arena>,
+    game_mode: Res<'w, GameMode>,
+    gameplay: Res<'w, GameplaySettings>,
+    theme: Res<'w, Theme>,
+    accessibility: Res<'w, AccessibilitySettings>,
+}
+
+/// Initial setup system - camera and score text.
 ///
-/// Runs once at app boot when `GameState::default()` is in `Menu` phase, so
-/// the start menu can be spawned unconditionally.
-fn setup_system(mut commands: Commands, high_score: Res<HighScore>) {
+/// The start menu itself is spawned by `show_menu_screen` on the first
+/// frame (`MenuScreen` counts as changed when it is first added), and the
+/// arena by `spawn_arena`.
+fn setup_system(mut commands: Commands, theme: Res<Theme>, effects: Res<EffectsSettings>) {
     // Setup camera with HDR and bloom for glowing effects
     commands.spawn((
         Camera2d,
         Hdr,
         Bloom {
-            intensity: 0.3,
+            intensity: BLOOM_INTENSITY * effects.bloom,
             low_frequency_boost: 0.6,
             low_frequency_boost_curvature: 0.5,
             high_pass_frequency: 0.8,
@@ -92,83 +191,269 @@ fn setup_system(mut commands: Commands, high_score: Res<HighScore>) {
         },
     ));
 
+    commands.spawn_scene(score_hud(&theme));
+}
+
+/// Keeps the [`Arena`] in step with the gameplay settings and the selected
+/// mode while on the menu, so the board behind it previews the next run.
+fn preview_arena(
+    game_state: Res<GameState>,
+    gameplay: Res<GameplaySettings>,
+    game_mode: Res<GameMode>,
+    mut arena: ResMut<Arena>,
+) {
+    if game_state.phase == GamePhase::Menu {
+        arena.set_if_neq(Arena::for_run(&gameplay, *game_mode));
+    }
+}
+
+/// How far the outside mask reaches past the arena, in world units — well
+/// beyond any window shape the camera can show.
+const ARENA_MASK_EXTENT: f32 = 100_000.0;
+
+/// (Re)builds the arena background, checkerboard, border and outside mask
+/// whenever the [`Arena`] changes — including the very first frame.
+///
+/// The pieces are tagged with their [`ThemeColor`] so theme switches repaint
+/// them.
+fn spawn_arena(
+    mut commands: Commands,
+    arena: Res<Arena>,
+    theme: Res<Theme>,
+    tiles: Query<Entity, With<ArenaTile>>,
+) {
+    if !arena.is_changed() {
+        return;
+    }
+    for entity in tiles.iter() {
+        commands.entity(entity).despawn();
+    }
+    let size = arena.world_size();
+
     // Arena background
     commands.spawn((
         Sprite {
-            color: ARENA_COLOR,
-            custom_size: Some(Vec2::new(
-                ARENA_WIDTH as f32 * CELL_SIZE,
-                ARENA_HEIGHT as f32 * CELL_SIZE,
-            )),
+            color: theme.arena,
+            custom_size: Some(size),
             ..default()
         },
         Transform::from_translation(Vec3::new(0.0, 0.0, Z_BACKGROUND)),
+        ThemeColor::Arena,
+        ArenaTile,
     ));
 
     // Subtle checkerboard over the arena so movement reads against the grid.
-    for x in 0..ARENA_WIDTH as i32 {
-        for y in 0..ARENA_HEIGHT as i32 {
+    for x in 0..arena.width as i32 {
+        for y in 0..arena.height as i32 {
             if (x + y) % 2 == 0 {
                 continue;
             }
+            let center = arena.to_world(Position { x, y });
             commands.spawn((
                 Sprite {
-                    color: ARENA_COLOR_ALT,
+                    color: theme.arena_alt,
                     custom_size: Some(Vec2::splat(CELL_SIZE)),
                     ..default()
                 },
-                Transform::from_xyz(
-                    (x as f32 - ARENA_WIDTH as f32 / 2.0 + 0.5) * CELL_SIZE,
-                    (y as f32 - ARENA_HEIGHT as f32 / 2.0 + 0.5) * CELL_SIZE,
-                    Z_BACKGROUND + 0.05,
-                ),
+                Transform::from_translation(center.extend(Z_BACKGROUND + 0.05)),
+                ThemeColor::ArenaAlt,
+                ArenaTile,
             ));
         }
     }
 
-    // Glowing arena border using hollow rectangle. Stays a plain spawn:
-    // `ShapeBundle` is a bundle, not a component, so it can't appear in `bsn!`.
-    let arena_width = ARENA_WIDTH as f32 * CELL_SIZE;
-    let arena_height = ARENA_HEIGHT as f32 * CELL_SIZE;
-    commands.spawn(ShapeBundle::rect(
-        &ShapeConfig {
-            color: ARENA_BORDER_COLOR,
-            alpha_mode: ShapeAlphaMode::Add,
-            hollow: true,
-            thickness: 2.0,
-            corner_radii: Vec4::splat(0.02),
-            transform: Transform::from_xyz(0.0, 0.0, 0.1),
-            ..ShapeConfig::default_2d()
-        },
-        Vec2::new(arena_width + 4.0, arena_height + 4.0),
+    // Clip everything to the arena: background-colored bands cover the
+    // outside, so a snake part sliding across a wrap-around edge (and its
+    // copy entering opposite, see `sync_wrap_ghosts`) is cut off exactly at
+    // the edge. The side bands also cover the corners.
+    let half = size / 2.0;
+    let side_band = Vec2::new(ARENA_MASK_EXTENT, size.y + 2.0 * ARENA_MASK_EXTENT);
+    let end_band = Vec2::new(size.x, ARENA_MASK_EXTENT);
+    let bands = [
+        (Vec2::new(-half.x - ARENA_MASK_EXTENT / 2.0, 0.0), side_band),
+        (Vec2::new(half.x + ARENA_MASK_EXTENT / 2.0, 0.0), side_band),
+        (Vec2::new(0.0, -half.y - ARENA_MASK_EXTENT / 2.0), end_band),
+        (Vec2::new(0.0, half.y + ARENA_MASK_EXTENT / 2.0), end_band),
+    ];
+    for (center, band) in bands {
+        commands.spawn((
+            Sprite {
+                color: theme.background,
+                custom_size: Some(band),
+                ..default()
+            },
+            Transform::from_translation(center.extend(Z_ARENA_MASK)),
+            ThemeColor::Background,
+            ArenaTile,
+        ));
+    }
+
+    // Glowing arena border using hollow rectangle, drawn above the mask.
+    // Stays a plain spawn: `ShapeBundle` is a bundle, not a component, so it
+    // can't appear in `bsn!`.
+    commands.spawn((
+        ShapeBundle::rect(
+            &ShapeConfig {
+                color: theme.arena_border,
+                alpha_mode: ShapeAlphaMode::Add,
+                hollow: true,
+                thickness: 2.0,
+                corner_radii: Vec4::splat(0.02),
+                transform: Transform::from_xyz(0.0, 0.0, Z_ARENA_MASK + 0.1),
+                ..ShapeConfig::default_2d()
+            },
+            size + Vec2::splat(4.0),
+        ),
+        ThemeColor::ArenaBorder,
+        ArenaTile,
     ));
+}
 
-    commands.spawn_scene(score_hud());
+/// Fits the [`Arena`], its padding and the HUD bar above it into the window
+/// at any window size, keeping the aspect ratio: the window's shorter side
+/// spans the view (`Arena::view_side`) and the longer one shows extra
+/// background. Smaller arenas get bigger cells, larger ones smaller cells.
+///
+/// The projection re-applies the fit by itself whenever the window (or the
+/// web canvas) is resized, so this only runs when the arena changes.
+fn fit_camera_to_arena(
+    arena: Res<Arena>,
+    mut cameras: Query<(&mut Projection, &mut Transform), With<Camera2d>>,
+) {
+    if !arena.is_changed() {
+        return;
+    }
+    let side = arena.view_side();
+    for (mut projection, mut transform) in &mut cameras {
+        if let Projection::Orthographic(ortho) = &mut *projection {
+            ortho.scaling_mode = ScalingMode::AutoMin {
+                min_width: side,
+                min_height: side,
+            };
+        }
+        transform.translation = arena.view_center().extend(transform.translation.z);
+    }
+}
 
-    // Show start menu (we're always in `Menu` phase at Startup).
-    commands.spawn_scene(start_menu(high_score.score));
+/// Toggles borderless fullscreen with F11. On the web this asks the browser
+/// to show the canvas fullscreen.
+fn toggle_fullscreen(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    if !keyboard_input.just_pressed(KeyCode::F11) {
+        return;
+    }
+    let Ok(mut window) = windows.single_mut() else {
+        return;
+    };
+    window.mode = match window.mode {
+        WindowMode::Windowed => WindowMode::BorderlessFullscreen(MonitorSelection::Current),
+        _ => WindowMode::Windowed,
+    };
 }
 
-/// The score HUD — hidden at boot (Menu phase) and toggled by
+/// The in-game HUD — hidden at boot (Menu phase) and toggled by
 /// `update_score_visibility` based on the current `GamePhase`.
-fn score_hud() -> impl Scene {
+///
+/// Left to right: score, best score, length, time played, speed, the Time
+/// Attack countdown, and a bar of how much of the board the snake fills
+/// (full means the run is won). `update_hud_text` fills them in. The best
+/// score is left out in Zen, which keeps no records, and the countdown is
+/// only laid out in Time Attack (`update_score_visibility` flips their
+/// `Display`).
+///
+/// The HUD is a bar along the top of a centered `100vmin` square — exactly
+/// the view `fit_camera_to_arena` fits into the window — so it sits right
+/// above the arena, lined up with its edges, and scales with it however
+/// the window is shaped. It never covers a cell.
+fn score_hud(theme: &Theme) -> impl Scene + use<> {
+    let (text_color, timer_color) = (theme.text, theme.record);
+    let (track_color, fill_color) = (theme.dim.with_alpha(0.35), theme.highlight);
+    // The arena is centered across the square, so its edges are half the
+    // padding and half the bar's height in from the square's.
+    let (font, gap, height, inset, bar) = (
+        view_vmin(14.0),
+        view_vmin(12.0),
+        view_vmin(HUD_HEIGHT),
+        view_vmin((WINDOW_PADDING + HUD_HEIGHT) / 2.0),
+        view_vmin(6.0),
+    );
+    let texts = bsn_list![
+        hud_label(bsn! { HudText::Score ThemeColor::Text }, font, text_color),
+        hud_label(bsn! { HudText::Best ThemeColor::Text }, font, text_color),
+        hud_label(bsn! { HudText::Length ThemeColor::Text }, font, text_color),
+        hud_label(bsn! { HudText::Clock ThemeColor::Text }, font, text_color),
+        hud_label(bsn! { HudText::Speed ThemeColor::Text }, font, text_color),
+        hud_label(
+            bsn! { TimerText ThemeColor::Record Node { display: Display::None } },
+            font,
+            timer_color
+        ),
+    ];
     bsn! {
-        ScoreText
-        Text("Score: 0")
-        TextFont {
-            font_size: { FontSize::Px(20.0) },
-            weight: FontWeight::BOLD,
-        }
-        TextColor(Color::WHITE)
+        HudUI
         Node {
             position_type: PositionType::Absolute,
-            top: px(10),
-            left: px(10),
+            width: percent(100),
+            height: percent(100),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
         }
         Visibility::Hidden
+        Children [(
+            Node {
+                width: { Val::VMin(100.0) },
+                height: { Val::VMin(100.0) },
+            }
+            Children [(
+                Node {
+                    width: percent(100),
+                    height: { Val::VMin(height) },
+                    padding: { UiRect::horizontal(Val::VMin(inset)) },
+                    column_gap: { Val::VMin(gap) },
+                    align_items: AlignItems::Center,
+                }
+                Children [
+                    { texts },
+                    (
+                        FillBar::Track
+                        Node {
+                            flex_grow: 1.0,
+                            height: { Val::VMin(bar) },
+                        }
+                        BackgroundColor(track_color)
+                        Children [(
+                            FillBar::Fill
+                            Node {
+                                width: percent(0),
+                                height: percent(100),
+                            }
+                            BackgroundColor(fill_color)
+                        )]
+                    ),
+                ]
+            )]
+        )]
     }
 }
 
+/// One bold text of the HUD bar, tagged with `tags`; empty until
+/// `update_hud_text` (or `update_timer_text`) fills it in.
+fn hud_label(tags: impl Scene, font_vmin: f32, color: Color) -> impl Scene {
+    (
+        tags,
+        bsn! {
+            Text("")
+            TextFont {
+                font_size: { FontSize::VMin(font_vmin) },
+                weight: FontWeight::BOLD,
+            }
+            TextColor(color)
+        },
+    )
+}
+
 /// Full-screen centered column over a translucent black scrim — the shared
 /// scaffold of the start menu and both end screens.
 fn overlay<L: SceneList>(scrim_alpha: f32, content: L) -> impl Scene {
@@ -191,10 +476,10 @@ fn overlay<L: SceneList>(scrim_alpha: f32, content: L) -> impl Scene {
 ///
 /// Font size and gap are in `vmin` units (percent of the window's smaller
 /// dimension) so the menu and end screens scale with the window instead of
-/// staying fixed at pixel sizes. At the default 520×520 window, 1 vmin =
-/// 5.2 px. The score HUD intentionally does *not* use this helper: it stays
-/// pixel-sized because it is tuned to the arena's fixed-size food-exclusion
-/// zone (`SCORE_AREA_COLS`/`SCORE_AREA_ROWS`).
+/// staying fixed at pixel sizes. At the default 560×560 window, 1 vmin =
+/// 5.6 px. The score HUD does *not* use this helper: its sizes are pixels
+/// of the default window, converted with `view_vmin` to fit the HUD bar
+/// (`HUD_HEIGHT`).
 fn label(text: String, size_vmin: f32, color: Color, gap_below_vmin: f32) -> impl Scene {
     bsn! {
         Text(text)
@@ -207,53 +492,108 @@ fn label(text: String, size_vmin: f32, color: Color, gap_below_vmin: f32) -> imp
     }
 }
 
-/// A clickable action button (START / RESTART / PLAY AGAIN).
+/// A clickable action button (START / RESTART / ACHIEVEMENTS / BACK ...).
+/// See [`sized_button`].
+fn action_button<M: Message + Clone>(
+    text: String,
+    request: M,
+    theme: &Theme,
+) -> impl Scene + use<M> {
+    sized_button(text, request, 4.6, theme)
+}
+
+/// One option of a selector row (e.g. the mode selector): a smaller button
+/// tagged with [`ChoiceButton`] so the selected option stays highlighted.
+fn choice_button<M: Message + Clone>(
+    text: String,
+    request: M,
+    selected: bool,
+    theme: &Theme,
+) -> impl Scene + use<M> {
+    (
+        sized_button(text, request, 3.2, theme),
+        bsn! { ChoiceButton { selected: { selected } } },
+    )
+}
+
+/// A horizontal row of buttons, centered on the overlay's column.
+fn button_row<L: SceneList>(content: L) -> impl Scene {
+    bsn! {
+        Node {
+            flex_direction: FlexDirection::Row,
+            column_gap: { Val::VMin(1.5) },
+            margin: { UiRect::bottom(Val::VMin(1.9)) },
+        }
+        Children [{ content }]
+    }
+}
+
+/// A clickable button with its label at `font_vmin`; padding, border and
+/// corner radius scale with it.
 ///
 /// Built on the headless `bevy_ui_widgets` button: the widget manages the
 /// `Pressed` state and emits [`Activate`] on click (and on Enter/Space when
-/// focused). The observer turns that into a [`StartRequested`] message so
-/// buttons and the SPACE key share one start/restart code path. `Hovered` is
-/// kept up to date by UI picking; `button_feedback` maps both states to
-/// background colors.
-fn action_button(text: String) -> impl Scene {
+/// focused). The observer turns that into the `request` message (e.g.
+/// [`StartRequested`]) so buttons and their keyboard shortcuts share one code
+/// path. `Hovered` is kept up to date by UI picking; `button_feedback` maps
+/// both states to background colors.
+fn sized_button<M: Message + Clone>(
+    text: String,
+    request: M,
+    font_vmin: f32,
+    theme: &Theme,
+) -> impl Scene + use<M> {
+    let (background, border, text_color) = (theme.button, theme.accent, theme.text);
     bsn! {
         WidgetButton
         Hovered
         Node {
-            padding: { UiRect::axes(Val::VMin(4.6), Val::VMin(1.7)) },
+            padding: { UiRect::axes(Val::VMin(font_vmin), Val::VMin(font_vmin * 0.37)) },
             border: { UiRect::all(Val::VMin(0.4)) },
-            border_radius: { BorderRadius::all(Val::VMin(1.9)) },
+            border_radius: { BorderRadius::all(Val::VMin(font_vmin * 0.41)) },
             justify_content: JustifyContent::Center,
             align_items: AlignItems::Center,
             margin: { UiRect::bottom(Val::VMin(1.9)) },
         }
-        BackgroundColor(BUTTON_BG)
-        BorderColor::from(START_GREEN)
-        on(|_: On<Activate>, mut requests: MessageWriter<StartRequested>| {
-            requests.write(StartRequested);
+        BackgroundColor(background)
+        BorderColor::from(border)
+        on(move |_: On<Activate>, mut requests: MessageWriter<M>| {
+            requests.write(request.clone());
         })
         Children [(
             Text(text)
             TextFont {
-                font_size: { FontSize::VMin(4.6) },
+                font_size: { FontSize::VMin(font_vmin) },
                 weight: FontWeight::BOLD,
             }
-            TextColor(Color::WHITE)
+            TextColor(text_color)
         )]
     }
 }
 
-/// Applies hover/pressed background feedback to the action buttons.
+/// Applies hover/pressed background feedback to the action buttons; the
+/// selected [`ChoiceButton`] rests at a brighter background.
 fn button_feedback(
-    mut buttons: Query<(&mut BackgroundColor, &Hovered, Has<Pressed>), With<WidgetButton>>,
+    theme: Res<Theme>,
+    mut buttons: Query<
+        (
+            &mut BackgroundColor,
+            &Hovered,
+            Has<Pressed>,
+            Option<&ChoiceButton>,
+        ),
+        With<WidgetButton>,
+    >,
 ) {
-    for (mut bg, hovered, pressed) in &mut buttons {
+    for (mut bg, hovered, pressed, choice) in &mut buttons {
         let target = if pressed {
-            BUTTON_BG_PRESSED
+            theme.button_pressed
         } else if hovered.get() {
-            BUTTON_BG_HOVERED
+            theme.button_hovered
+        } else if choice.is_some_and(|choice| choice.selected) {
+            theme.button_selected
         } else {
-            BUTTON_BG
+            theme.button
         };
         if bg.0 != target {
             bg.0 = target;
@@ -261,56 +601,435 @@ fn button_feedback(
     }
 }
 
+/// Fades end screens tagged with [`OverlayFadeIn`] in: the scrim up to
+/// [`END_SCREEN_SCRIM`], and the text, buttons and borders on it up from
+/// transparent.
+fn fade_in_overlays(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut overlays: Query<(Entity, &mut OverlayFadeIn)>,
+    children: Query<&Children>,
+    // The sparkline's bars stay opaque: with no `button_feedback` to restore
+    // them, scaling their alpha every frame would compound.
+    mut parts: Query<
+        (
+            Option<&mut BackgroundColor>,
+            Option<&mut BorderColor>,
+            Option<&mut TextColor>,
+        ),
+        Without<SparkBar>,
+    >,
+) {
+    for (root, mut fade) in &mut overlays {
+        fade.timer.tick(time.delta());
+        let t = fade.timer.fraction();
+        if fade.timer.is_finished() {
+            commands.entity(root).remove::<OverlayFadeIn>();
+        }
+
+        if let Ok((Some(mut scrim), _, _)) = parts.get_mut(root) {
+            scrim.0.set_alpha(END_SCREEN_SCRIM * t);
+        }
+        for entity in children.iter_descendants(root) {
+            let Ok((background, border, text)) = parts.get_mut(entity) else {
+                continue;
+            };
+            // `button_feedback` restores button backgrounds every frame, so
+            // scaling the current alpha never compounds.
+            if let Some(mut background) = background {
+                let alpha = background.0.alpha();
+                background.0.set_alpha(alpha * t);
+            }
+            if let Some(mut border) = border {
+                *border = BorderColor::from(border.top.with_alpha(t));
+            }
+            // Overlay text is always opaque.
+            if let Some(mut text) = text {
+                text.0.set_alpha(t);
+            }
+        }
+    }
+}
+
 /// The start menu screen.
 ///
 /// The `(marker, scene)` tuples here and in the end screens merge both parts
 /// onto the same root entity — tuples of scenes implement [`Scene`].
-fn start_menu(high_score: usize) -> impl Scene {
+fn start_menu(
+    mode: GameMode,
+    best: usize,
+    zen_bite: ZenBite,
+    daily: &DailyRecords,
+    move_keys: String,
+    theme: &Theme,
+    locale: &Locale,
+) -> impl Scene + use<> {
+    let mode_buttons: Vec<_> = GameMode::choices()
+        .into_iter()
+        .map(|choice| {
+            let text = mode_label(choice, locale);
+            choice_button(text, SelectMode(choice), choice == mode, theme)
+        })
+        .collect();
+    // The bite-rule row only exists while Zen is selected.
+    let zen_buttons: Vec<_> = if mode == GameMode::Zen {
+        [
+            ("zen.pass_through", ZenBite::PassThrough),
+            ("zen.cut_tail", ZenBite::CutTail),
+        ]
+        .into_iter()
+        .map(|(key, bite)| {
+            choice_button(locale.text(key), SetZenBite(bite), bite == zen_bite, theme)
+        })
+        .collect()
+    } else {
+        Vec::new()
+    };
+    // Today's attempt status and the last few days, only while Daily is
+    // selected.
+    let daily_lines: Vec<_> = if mode == GameMode::Daily {
+        daily_status(daily, locale)
+            .into_iter()
+            .map(|line| label(line, 2.9, theme.hint, 1.0))
+            .collect()
+    } else {
+        Vec::new()
+    };
+    // Sub-screens reachable from the main menu, side by side.
+    let screen_buttons = bsn_list![
+        action_button(
+            locale.text("menu.achievements"),
+            MenuNavigate(MenuScreen::Achievements),
+            theme
+        ),
+        action_button(
+            locale.text("menu.settings"),
+            MenuNavigate(MenuScreen::Settings(SettingsPage::default())),
+            theme
+        ),
+    ];
     (
         bsn! { MenuUI },
         overlay(
             0.85,
             bsn_list![
-                label("SNAKE".into(), 15.4, TITLE_GREEN, 7.7),
-                { menu_high_score(high_score) },
-                label("CONTROLS".into(), 4.6, Color::WHITE, 2.9),
-                label("Arrow Keys or WASD to move".into(), 3.5, HINT_GRAY, 1.9),
-                label("Eat the red apples to grow".into(), 3.5, HINT_GRAY, 1.9),
-                label("Don't run into yourself!".into(), 3.5, HINT_GRAY, 7.7),
-                action_button("START".into()),
-                label("or press SPACE".into(), 2.9, BEST_GRAY, 0.0),
+                label(locale.text("menu.title"), 15.4, theme.title, 4.6),
+                { menu_high_score(best, theme, locale) },
+                label(locale.text("menu.controls"), 4.6, theme.text, 2.9),
+                label(move_keys, 3.5, theme.hint, 1.9),
+                label(locale.text("menu.eat_hint"), 3.5, theme.hint, 1.9),
+                label(locale.text("menu.avoid_hint"), 3.5, theme.hint, 4.6),
+                label(mode_hint(mode, locale), 3.2, theme.highlight, 1.5),
+                button_row({ mode_buttons }),
+                button_row({ zen_buttons }),
+                { daily_lines },
+                action_button(locale.text("menu.start"), StartRequested, theme),
+                button_row({ screen_buttons }),
+                label(locale.text("menu.press_space"), 2.9, theme.dim, 0.0),
             ],
         ),
     )
 }
 
-/// Persistent best score from previous sessions — only shown once the player
-/// has actually scored something (`None` spawns nothing).
-fn menu_high_score(high_score: usize) -> Option<impl SceneList> {
-    (high_score > 0)
-        .then(|| bsn_list![label(format!("High Score: {high_score}"), 3.8, MENU_GOLD, 5.8)])
+/// The achievements gallery: every entry of the table, unlocked ones in gold
+/// and locked ones dimmed, with a BACK button to the main menu.
+fn achievements_gallery(
+    unlocked: &UnlockedAchievements,
+    theme: &Theme,
+    locale: &Locale,
+) -> impl Scene + use<> {
+    let unlocked_count = ACHIEVEMENTS
+        .iter()
+        .filter(|a| unlocked.contains(a.id))
+        .count();
+    let rows: Vec<_> = ACHIEVEMENTS
+        .iter()
+        .map(|achievement| {
+            let (mark, color) = if unlocked.contains(achievement.id) {
+                ("[x]", theme.record)
+            } else {
+                ("[ ]", theme.locked)
+            };
+            label(
+                format!(
+                    "{mark} {} - {}",
+                    achievement.title(locale),
+                    achievement.description(locale)
+                ),
+                3.2,
+                color,
+                1.5,
+            )
+        })
+        .collect();
+    (
+        bsn! { MenuUI },
+        overlay(
+            0.85,
+            bsn_list![
+                label(locale.text("achievements.title"), 8.5, theme.title, 2.9),
+                label(
+                    locale.format(
+                        "achievements.unlocked",
+                        &[
+                            ("unlocked", &unlocked_count),
+                            ("total", &ACHIEVEMENTS.len())
+                        ]
+                    ),
+                    3.5,
+                    theme.hint,
+                    4.6
+                ),
+                { rows },
+                label(String::new(), 1.0, theme.hint, 3.8),
+                action_button(
+                    locale.text("menu.back"),
+                    MenuNavigate(MenuScreen::Main),
+                    theme
+                ),
+                label(locale.text("menu.press_esc"), 2.9, theme.dim, 0.0),
+            ],
+        ),
+    )
 }
 
-/// Shared layout of the game-over and win screens: title, final score,
-/// record comparison, restart hint.
+/// A mode's label on the mode selector.
+pub(crate) fn mode_label(mode: GameMode, locale: &Locale) -> String {
+    match mode {
+        GameMode::TimeAttack { limit } => {
+            locale.format(mode.label_key(), &[("seconds", &limit.seconds())])
+        }
+        GameMode::Classic | GameMode::Zen | GameMode::Daily => locale.text(mode.label_key()),
+    }
+}
+
+/// One line under the controls describing the selected mode's rules.
+fn mode_hint(mode: GameMode, locale: &Locale) -> String {
+    match mode {
+        GameMode::Classic => locale.text("mode_hint.classic"),
+        GameMode::TimeAttack { limit } => {
+            locale.format("mode_hint.time_attack", &[("seconds", &limit.seconds())])
+        }
+        GameMode::Zen => locale.text("mode_hint.zen"),
+        GameMode::Daily => locale.format("mode_hint.daily", &[("date", &daily::today())]),
+    }
+}
+
+/// Menu lines for the Daily mode: whether today's scored attempt is still
+/// available, then up to three previous days' results, newest first.
+fn daily_status(daily: &DailyRecords, locale: &Locale) -> Vec<String> {
+    let today = daily::today();
+    let mut lines = vec![match daily.for_date(&today) {
+        Some(result) => locale.plural("daily.today_score", result.score),
+        None => locale.text("daily.one_attempt"),
+    }];
+    lines.extend(
+        daily
+            .history
+            .iter()
+            .rev()
+            .filter(|result| result.date != today)
+            .take(3)
+            .map(|result| {
+                locale.format(
+                    "daily.history",
+                    &[("date", &result.date), ("score", &result.score)],
+                )
+            }),
+    );
+    lines
+}
+
+/// Applies [`SetZenBite`] requests from the Zen bite-rule buttons and
+/// persists the choice.
+fn select_zen_bite(
+    mut saves: MessageWriter<SaveRequested>,
+    mut requests: MessageReader<SetZenBite>,
+    game_state: Res<GameState>,
+    mut zen: ResMut<ZenSettings>,
+) {
+    // Drain unconditionally so a request from another phase can't linger.
+    let target = requests.read().last().map(|request| request.0);
+    if game_state.phase != GamePhase::Menu {
+        return;
+    }
+    if let Some(target) = target
+        && zen.on_self_bite != target
+    {
+        zen.on_self_bite = target;
+        saves.write(SaveRequested);
+    }
+}
+
+/// Applies [`SelectMode`] requests from the mode selector, and lets
+/// Left/Right cycle through the modes on the main menu screen.
+fn select_mode(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut requests: MessageReader<SelectMode>,
+    game_state: Res<GameState>,
+    menu_screen: Res<MenuScreen>,
+    mut game_mode: ResMut<GameMode>,
+) {
+    // Drain unconditionally so a request from another phase can't linger.
+    let mut target = requests.read().last().map(|request| request.0);
+    if game_state.phase != GamePhase::Menu || *menu_screen != MenuScreen::Main {
+        return;
+    }
+
+    let choices = GameMode::choices();
+    let current = choices.iter().position(|&m| m == *game_mode).unwrap_or(0);
+    if keyboard_input.just_pressed(KeyCode::ArrowRight) {
+        target = Some(choices[(current + 1) % choices.len()]);
+    } else if keyboard_input.just_pressed(KeyCode::ArrowLeft) {
+        target = Some(choices[(current + choices.len() - 1) % choices.len()]);
+    }
+    if let Some(target) = target {
+        game_mode.set_if_neq(target);
+    }
+}
+
+/// Applies [`MenuNavigate`] requests from the menu buttons, and lets ESC step
+/// back from a sub-screen to the main menu.
+fn navigate_menu(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut requests: MessageReader<MenuNavigate>,
+    game_state: Res<GameState>,
+    mut menu_screen: ResMut<MenuScreen>,
+) {
+    // Drain unconditionally so a request from another phase can't linger.
+    let mut target = requests.read().last().map(|request| request.0);
+    if keyboard_input.just_pressed(KeyCode::Escape) && *menu_screen != MenuScreen::Main {
+        target = Some(MenuScreen::Main);
+    }
+    if game_state.phase != GamePhase::Menu {
+        return;
+    }
+    if let Some(target) = target {
+        // `set_if_neq` so re-selecting the current screen doesn't rebuild it.
+        menu_screen.set_if_neq(target);
+    }
+}
+
+/// (Re)spawns the menu overlay for the current [`MenuScreen`] whenever it,
+/// the selected [`GameMode`], the Zen bite rule, the theme, the language, or
+/// anything the settings screen shows changes while in the `Menu` phase —
+/// including the very first frame.
+#[allow(clippy::too_many_arguments)]
+fn show_menu_screen(
+    mut commands: Commands,
+    game_state: Res<GameState>,
+    menu_screen: Res<MenuScreen>,
+    game_mode: Res<GameMode>,
+    zen: Res<ZenSettings>,
+    records: Records,
+    unlocked: Res<UnlockedAchievements>,
+    theme: Res<Theme>,
+    locale: Res<Locale>,
+    settings: SettingsView,
+    menu_ui: Query<Entity, With<MenuUI>>,
+) {
+    let stale = menu_screen.is_changed()
+        || game_mode.is_changed()
+        || zen.is_changed()
+        || theme.is_changed()
+        || locale.is_changed()
+        || settings.is_changed();
+    if game_state.phase != GamePhase::Menu || !stale {
+        return;
+    }
+    for entity in menu_ui.iter() {
+        commands.entity(entity).despawn();
+    }
+    let best = records.best(*game_mode);
+    match *menu_screen {
+        MenuScreen::Main => commands.spawn_scene(start_menu(
+            *game_mode,
+            best,
+            zen.on_self_bite,
+            &records.daily,
+            settings.move_keys_hint(),
+            &theme,
+            &locale,
+        )),
+        MenuScreen::Achievements => {
+            commands.spawn_scene(achievements_gallery(&unlocked, &theme, &locale))
+        }
+        MenuScreen::Settings(page) => {
+            commands.spawn_scene(settings_screen(page, &settings, &theme))
+        }
+    };
+}
+
+/// Persistent best score from previous sessions for the selected mode — only
+/// shown once the player has actually scored something (`None` spawns
+/// nothing).
+fn menu_high_score(
+    high_score: usize,
+    theme: &Theme,
+    locale: &Locale,
+) -> Option<impl SceneList + use<>> {
+    (high_score > 0).then(|| {
+        bsn_list![label(
+            locale.plural("menu.high_score", high_score),
+            3.8,
+            theme.highlight,
+            5.8
+        )]
+    })
+}
+
+/// How a finished run relates to the stored records, for its end screen.
+struct RunResult {
+    score: usize,
+    /// The mode's record as it was *before* this run is persisted.
+    previous_best: usize,
+    /// Whether this run is eligible to set a record at all (a Time Attack
+    /// run that dies before time is up is not).
+    counts: bool,
+    /// Replaces the record comparison for modes without a plain record
+    /// (Zen practice, Daily attempts).
+    note: Option<String>,
+    stats: RunStats,
+    /// Time the run was played for.
+    duration: Duration,
+}
+
+/// Shared layout of the game-over, win, and time-up screens: title, what
+/// killed the snake (if it died), final score, record comparison, the run
+/// summary and its toggle, restart and menu buttons.
 ///
 /// The scrim alpha is high enough to make the overlay text dominant, but
 /// still translucent so the player can see where they died.
 fn end_screen(
     title: String,
     title_color: Color,
-    score: usize,
-    previous_best: usize,
+    result: RunResult,
     button_text: String,
-) -> impl Scene {
+    theme: &Theme,
+    locale: &Locale,
+) -> impl Scene + use<> {
+    let summary = run_summary(&result.stats, result.duration, theme, locale);
+    let cause = result
+        .stats
+        .death
+        .map(|death| bsn_list![label(death_text(&death, locale), 3.8, theme.text, 2.3)]);
     overlay(
-        0.82,
+        END_SCREEN_SCRIM,
         bsn_list![
             label(title, 11.5, title_color, 3.8),
-            label(format!("Final Score: {}", score), 5.8, Color::WHITE, 2.3),
-            record_line(score, previous_best),
-            action_button(button_text),
-            label("or press SPACE".into(), 2.9, BEST_GRAY, 0.0),
+            { cause },
+            label(
+                locale.plural("end.final_score", result.score),
+                5.8,
+                theme.text,
+                2.3
+            ),
+            record_line(result, theme, locale),
+            sized_button(locale.text("end.summary"), ToggleRunSummary, 3.2, theme),
+            summary,
+            action_button(button_text, StartRequested, theme),
+            action_button(locale.text("end.menu"), ReturnToMenu, theme),
+            label(locale.text("end.press_keys"), 2.9, theme.dim, 0.0),
         ],
     )
 }
@@ -321,201 +1040,671 @@ fn end_screen(
 ///
 /// Callers must pass the record as it was *before* this run is persisted —
 /// see the ordering note on `update_high_score` in the plugin's system chain.
-fn record_line(score: usize, previous_best: usize) -> impl Scene {
-    let (text, color) = if score > previous_best {
-        ("NEW HIGH SCORE!".to_string(), RECORD_GOLD)
+fn record_line(result: RunResult, theme: &Theme, locale: &Locale) -> impl Scene + use<> {
+    let RunResult {
+        score,
+        previous_best,
+        counts,
+        note,
+        ..
+    } = result;
+    let (text, color) = if let Some(note) = note {
+        (note, theme.dim)
+    } else if counts && score > previous_best {
+        (locale.text("end.new_high_score"), theme.record)
     } else {
-        (format!("Best: {}", previous_best), BEST_GRAY)
+        (locale.plural("end.best", previous_best), theme.dim)
     };
     label(text, 4.2, color, 5.8)
 }
 
-/// The game over screen.
-fn game_over_screen(score: usize, previous_best: usize) -> impl Scene {
+/// What killed the snake, as a sentence for the end screens.
+fn death_text(death: &DeathCause, locale: &Locale) -> String {
+    match death.kind {
+        DeathKind::SelfBite { segment } => {
+            locale.format("death.self_bite", &[("segment", &segment)])
+        }
+        DeathKind::Wall => locale.text("death.wall"),
+    }
+}
+
+/// The run summary of an end screen: the run's stats in two columns, what
+/// ended it, and a sparkline of the score. Hidden until
+/// `toggle_run_summary` shows it.
+fn run_summary(
+    stats: &RunStats,
+    duration: Duration,
+    theme: &Theme,
+    locale: &Locale,
+) -> impl Scene + use<> {
+    let minutes = duration.as_secs_f32() / 60.0;
+    let rate = if minutes > 0.0 {
+        stats.apples as f32 / minutes
+    } else {
+        0.0
+    };
+    let closest_call = match stats.closest_call {
+        Some(exits) => locale.plural("summary.closest_call", exits),
+        None => locale.text("summary.no_close_call"),
+    };
+    let cause = match &stats.death {
+        Some(death) => death_text(death, locale),
+        None => locale.text("summary.survived"),
+    };
+    let line = |text: String| label(text, 3.0, theme.text, 1.0);
+    let left = bsn_list![
+        line(locale.format("summary.duration", &[("time", &clock(duration.as_secs()))])),
+        line(locale.format(
+            "summary.apples_per_minute",
+            &[("rate", &format!("{rate:.1}"))]
+        )),
+        line(locale.format("summary.turns", &[("turns", &stats.turns)])),
+    ];
+    let right = bsn_list![
+        line(locale.format(
+            "summary.longest_straight",
+            &[("cells", &stats.longest_straight)]
+        )),
+        line(closest_call),
+    ];
+    let ending = bsn_list![line(cause)];
+    let bar_color = theme.highlight;
+    let bars: Vec<_> = sparkline(&stats.score_history, duration)
+        .into_iter()
+        .map(|height| {
+            bsn! {
+                SparkBar
+                Node {
+                    width: { Val::VMin(1.4) },
+                    height: { percent(height * 100.0) },
+                    min_height: { Val::VMin(0.4) },
+                }
+                BackgroundColor(bar_color)
+            }
+        })
+        .collect();
+    bsn! {
+        RunSummaryPanel
+        Node {
+            display: Display::None,
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            margin: { UiRect::bottom(Val::VMin(1.9)) },
+        }
+        Children [
+            (
+                Node {
+                    flex_direction: FlexDirection::Row,
+                    column_gap: { Val::VMin(5.0) },
+                }
+                Children [
+                    (Node { flex_direction: FlexDirection::Column } Children [{ left }]),
+                    (Node { flex_direction: FlexDirection::Column } Children [{ right }]),
+                ]
+            ),
+            { ending },
+            (
+                Node {
+                    height: { Val::VMin(7.0) },
+                    column_gap: { Val::VMin(0.4) },
+                    align_items: AlignItems::FlexEnd,
+                    margin: { UiRect::top(Val::VMin(0.8)) },
+                }
+                Children [{ bars }]
+            ),
+        ]
+    }
+}
+
+/// The score at the end of each of [`SPARKLINE_COLUMNS`] equal slices of a
+/// run lasting `duration`, as a fraction of its best score.
+fn sparkline(history: &[(Duration, usize)], duration: Duration) -> Vec<f32> {
+    let peak = history.iter().map(|&(_, score)| score).max().unwrap_or(0);
+    (1..=SPARKLINE_COLUMNS)
+        .map(|column| {
+            let until = duration * column / SPARKLINE_COLUMNS;
+            let score = history
+                .iter()
+                .take_while(|&&(at, _)| at <= until)
+                .last()
+                .map_or(0, |&(_, score)| score);
+            score as f32 / peak.max(1) as f32
+        })
+        .collect()
+}
+
+/// The game over screen. It fades in, following on from the death sequence.
+fn game_over_screen(result: RunResult, theme: &Theme, locale: &Locale) -> impl Scene + use<> {
     (
-        bsn! { GameOverUI },
+        bsn! {
+            GameOverUI
+            OverlayFadeIn {
+                timer: { Timer::from_seconds(GAME_OVER_FADE_SECONDS, TimerMode::Once) },
+            }
+        },
         end_screen(
-            "GAME OVER".into(),
-            GAME_OVER_RED,
-            score,
-            previous_best,
-            "RESTART".into(),
+            locale.text("end.game_over"),
+            theme.danger,
+            result,
+            locale.text("end.restart"),
+            theme,
+            locale,
         ),
     )
 }
 
 /// The win screen, shown when the player fills the arena.
-fn win_screen(score: usize, previous_best: usize) -> impl Scene {
+fn win_screen(result: RunResult, theme: &Theme, locale: &Locale) -> impl Scene + use<> {
     (
         bsn! { WinUI },
         end_screen(
-            "YOU WIN!".into(),
-            TITLE_GREEN,
-            score,
-            previous_best,
-            "PLAY AGAIN".into(),
+            locale.text("end.win"),
+            theme.title,
+            result,
+            locale.text("end.play_again"),
+            theme,
+            locale,
         ),
     )
 }
 
-/// System to spawn game over screen when game ends.
+/// The time-up screen, shown when a Time Attack countdown runs out.
+fn time_up_screen(result: RunResult, theme: &Theme, locale: &Locale) -> impl Scene + use<> {
+    (
+        bsn! { TimeUpUI },
+        end_screen(
+            locale.text("end.time_up"),
+            theme.record,
+            result,
+            locale.text("end.play_again"),
+            theme,
+            locale,
+        ),
+    )
+}
+
+/// Whether a run that ended in `phase` under `mode` may set that mode's
+/// record. Time Attack only counts runs that survive to the buzzer (or win).
+fn run_counts(mode: GameMode, phase: GamePhase) -> bool {
+    match mode {
+        GameMode::Classic => matches!(phase, GamePhase::GameOver | GamePhase::Won),
+        GameMode::TimeAttack { .. } => matches!(phase, GamePhase::TimeUp | GamePhase::Won),
+        // Daily attempts are recorded per day by `track_daily_run`.
+        GameMode::Zen | GameMode::Daily => false,
+    }
+}
+
+/// Builds the [`RunResult`] for the run that just ended.
+fn run_result(
+    game_state: &GameState,
+    mode: GameMode,
+    records: &Records,
+    daily_run: &DailyRun,
+    report: &RunReport,
+    locale: &Locale,
+) -> RunResult {
+    let note = match mode {
+        GameMode::Zen => Some(locale.text("end.practice")),
+        GameMode::Daily if daily_run.scored => {
+            Some(locale.format("end.daily_recorded", &[("date", &daily_run.date)]))
+        }
+        GameMode::Daily => Some(locale.text("end.daily_replay")),
+        GameMode::Classic | GameMode::TimeAttack { .. } => None,
+    };
+    RunResult {
+        score: game_state.score,
+        previous_best: records.best(mode),
+        counts: run_counts(mode, game_state.phase),
+        note,
+        stats: report.stats.clone(),
+        duration: report.clock.elapsed,
+    }
+}
+
+/// System to spawn game over screen once the [`DeathSequence`] has played.
+///
+/// The result is taken the frame the game ends, while `update_high_score`
+/// hasn't stored a new record yet, and held until the screen is shown.
+#[allow(clippy::too_many_arguments)]
 fn spawn_game_over_screen_system(
     mut commands: Commands,
     game_state: Res<GameState>,
+    game_mode: Res<GameMode>,
     game_over_ui: Query<Entity, With<GameOverUI>>,
-    high_score: Res<HighScore>,
+    records: Records,
+    daily_run: Res<DailyRun>,
+    report: RunReport,
+    death: Res<DeathSequence>,
+    theme: Res<Theme>,
+    locale: Res<Locale>,
+    mut pending: Local<Option<RunResult>>,
 ) {
-    // Only spawn if game just ended and no UI exists yet
+    // Only take the result if game just ended and no UI exists yet
     if game_state.is_changed() && game_state.phase == GamePhase::GameOver && game_over_ui.is_empty()
     {
-        commands.spawn_scene(game_over_screen(game_state.score, high_score.score));
+        *pending = Some(run_result(
+            &game_state,
+            *game_mode,
+            &records,
+            &daily_run,
+            &report,
+            &locale,
+        ));
+    }
+    if !death.is_playing()
+        && let Some(result) = pending.take()
+    {
+        commands.spawn_scene(game_over_screen(result, &theme, &locale));
     }
 }
 
 /// System to spawn the win screen when the player fills the arena.
+#[allow(clippy::too_many_arguments)]
 fn spawn_win_screen_system(
     mut commands: Commands,
     game_state: Res<GameState>,
+    game_mode: Res<GameMode>,
     win_ui: Query<Entity, With<WinUI>>,
-    high_score: Res<HighScore>,
+    records: Records,
+    daily_run: Res<DailyRun>,
+    report: RunReport,
+    theme: Res<Theme>,
+    locale: Res<Locale>,
 ) {
     if game_state.is_changed() && game_state.phase == GamePhase::Won && win_ui.is_empty() {
-        commands.spawn_scene(win_screen(game_state.score, high_score.score));
+        let result = run_result(
+            &game_state,
+            *game_mode,
+            &records,
+            &daily_run,
+            &report,
+            &locale,
+        );
+        commands.spawn_scene(win_screen(result, &theme, &locale));
+    }
+}
+
+/// System to spawn the time-up screen when a Time Attack countdown ends.
+#[allow(clippy::too_many_arguments)]
+fn spawn_time_up_screen_system(
+    mut commands: Commands,
+    game_state: Res<GameState>,
+    game_mode: Res<GameMode>,
+    time_up_ui: Query<Entity, With<TimeUpUI>>,
+    records: Records,
+    daily_run: Res<DailyRun>,
+    report: RunReport,
+    theme: Res<Theme>,
+    locale: Res<Locale>,
+) {
+    if game_state.is_changed() && game_state.phase == GamePhase::TimeUp && time_up_ui.is_empty() {
+        let result = run_result(
+            &game_state,
+            *game_mode,
+            &records,
+            &daily_run,
+            &report,
+            &locale,
+        );
+        commands.spawn_scene(time_up_screen(result, &theme, &locale));
     }
 }
 
-/// Persists a new record when a run ends (game over or win).
+/// Shows or hides the end screen's run summary, on its SUMMARY button or TAB.
+fn toggle_run_summary(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut requests: MessageReader<ToggleRunSummary>,
+    game_state: Res<GameState>,
+    mut panels: Query<&mut Node, With<RunSummaryPanel>>,
+) {
+    // Drain unconditionally so a request from another phase can't linger.
+    let clicked = requests.read().count() > 0;
+    if !run_finished(game_state.phase) || !(clicked || keyboard_input.just_pressed(KeyCode::Tab)) {
+        return;
+    }
+    for mut node in &mut panels {
+        node.display = match node.display {
+            Display::None => Display::Flex,
+            _ => Display::None,
+        };
+    }
+}
+
+/// Persists a new record when a run that counts for its mode ends — the
+/// classic [`HighScore`] or the matching [`TimeAttackRecords`] slot.
 ///
 /// Ordered after the end-screen spawn systems in the plugin's chain so those
 /// systems still see the previous record when deciding whether to show
-/// "NEW HIGH SCORE!". The save is asynchronous (file I/O happens on another
-/// thread) and crash-safe: `bevy_settings` writes to a temp file and renames.
+/// "NEW HIGH SCORE!". A new record asks for the settings file to be saved.
 fn update_high_score(
-    mut commands: Commands,
+    mut saves: MessageWriter<SaveRequested>,
     game_state: Res<GameState>,
+    game_mode: Res<GameMode>,
     mut high_score: ResMut<HighScore>,
+    mut time_attack: ResMut<TimeAttackRecords>,
 ) {
-    let run_ended = game_state.is_changed()
-        && matches!(game_state.phase, GamePhase::GameOver | GamePhase::Won);
-    if run_ended && game_state.score > high_score.score {
-        high_score.score = game_state.score;
-        commands.queue(SaveSettings::IfChanged);
+    if !game_state.is_changed() || !run_counts(*game_mode, game_state.phase) {
+        return;
+    }
+    let record = match *game_mode {
+        GameMode::Classic => &mut high_score.score,
+        GameMode::TimeAttack { limit } => time_attack.best_mut(limit),
+        // Unreachable: `run_counts` never accepts a Zen or Daily run.
+        GameMode::Zen | GameMode::Daily => return,
+    };
+    if game_state.score > *record {
+        *record = game_state.score;
+        saves.write(SaveRequested);
     }
 }
 
 /// Resets all shared game state, deterministically clears any leftover camera
-/// shake, and spawns a fresh snake head and food.
+/// shake, reseeds the food RNG for the mode, and spawns a fresh snake head
+/// and food.
 ///
 /// Called by both `start_game_from_menu` and `restart_game`.
-fn begin_new_game(
-    commands: &mut Commands,
-    game_state: &mut GameState,
-    camera_shake: &mut CameraShake,
-) {
+fn begin_new_game(commands: &mut Commands, run: &mut RunReset) {
+    let game_state = &mut *run.game_state;
     game_state.snake_segments.clear();
     game_state.score = 0;
     game_state.phase = GamePhase::Playing;
+    *run.run_clock = RunClock::default();
 
     // Cancel any leftover camera shake so the new game doesn't start mid-shake.
-    camera_shake.timer = Timer::from_seconds(0.0, TimerMode::Once);
-    camera_shake.intensity = 0.0;
+    run.camera_shake.timer = Timer::from_seconds(0.0, TimerMode::Once);
+    run.camera_shake.intensity = 0.0;
+
+    // Settings changed on the menu apply from here on, never mid-run.
+    run.arena
+        .set_if_neq(Arena::for_run(&run.gameplay, *run.game_mode));
+
+    // Daily runs replay the day's apple sequence; everything else is random.
+    *run.food_rng = match *run.game_mode {
+        GameMode::Daily => FoodRng::daily(daily::seed_for(&daily::today())),
+        _ => FoodRng::random(),
+    };
 
-    let head_entity = spawn_snake_head(commands);
+    let head_entity = spawn_snake_head(commands, &run.arena, &run.theme);
     game_state.snake_segments.push(head_entity);
-    spawn_food(commands, &[INITIAL_SNAKE_POSITION]);
+    spawn_food(
+        commands,
+        &mut run.food_rng,
+        &run.arena,
+        &run.theme,
+        &run.accessibility,
+        &[INITIAL_SNAKE_POSITION],
+    );
 }
 
 /// System to start the game from the menu, on SPACE or the START button.
+///
+/// SPACE only starts from the main screen, not from a sub-screen such as the
+/// achievements gallery.
 fn start_game_from_menu(
     mut commands: Commands,
     keyboard_input: Res<ButtonInput<KeyCode>>,
     mut start_requests: MessageReader<StartRequested>,
-    mut game_state: ResMut<GameState>,
-    mut camera_shake: ResMut<CameraShake>,
+    mut run: RunReset,
+    menu_screen: Res<MenuScreen>,
     menu_ui: Query<Entity, With<MenuUI>>,
 ) {
     // Drain unconditionally so a request from another phase can't linger.
     let button_clicked = start_requests.read().count() > 0;
     let requested = button_clicked || keyboard_input.just_pressed(KeyCode::Space);
-    if game_state.phase == GamePhase::Menu && requested {
+    if run.game_state.phase == GamePhase::Menu && *menu_screen == MenuScreen::Main && requested {
         for entity in menu_ui.iter() {
             commands.entity(entity).despawn();
         }
-        begin_new_game(&mut commands, &mut game_state, &mut camera_shake);
+        begin_new_game(&mut commands, &mut run);
+    }
+}
+
+/// Whether the run is over. It can only be left once its end screen is up.
+fn run_finished(phase: GamePhase) -> bool {
+    matches!(
+        phase,
+        GamePhase::GameOver | GamePhase::Won | GamePhase::TimeUp
+    )
+}
+
+/// Despawns the finished run's snake, food, any in-flight eating effects
+/// (flashes, particles, score popups), the death highlight, and the
+/// end-screen overlay, so whatever comes next starts visually clean.
+fn clear_finished_run(
+    commands: &mut Commands,
+    segments: &SnakeEntityQuery,
+    food: &Query<Entity, With<Food>>,
+    effects: &EffectEntityQuery,
+    end_screens: &EndScreenQuery,
+) {
+    for entity in segments
+        .iter()
+        .chain(food.iter())
+        .chain(effects.iter())
+        .chain(end_screens.iter())
+    {
+        commands.entity(entity).despawn();
     }
 }
 
-/// System to restart the game from the game-over or win screen, on SPACE or
-/// the RESTART / PLAY AGAIN button.
+/// System to restart the game from an end screen, on SPACE or the
+/// RESTART / PLAY AGAIN button. The next run keeps the same [`GameMode`].
 #[allow(clippy::too_many_arguments)]
 fn restart_game(
     mut commands: Commands,
     keyboard_input: Res<ButtonInput<KeyCode>>,
     mut start_requests: MessageReader<StartRequested>,
-    mut game_state: ResMut<GameState>,
+    mut run: RunReset,
     mut input_buffer: ResMut<InputBuffer>,
-    mut camera_shake: ResMut<CameraShake>,
     segments: SnakeEntityQuery,
     food: Query<Entity, With<Food>>,
     effects: EffectEntityQuery,
-    game_over_ui: Query<Entity, With<GameOverUI>>,
-    win_ui: Query<Entity, With<WinUI>>,
+    end_screens: EndScreenQuery,
 ) {
     // Drain unconditionally so a request from another phase can't linger.
     let button_clicked = start_requests.read().count() > 0;
     let requested = button_clicked || keyboard_input.just_pressed(KeyCode::Space);
-    let restartable = matches!(game_state.phase, GamePhase::GameOver | GamePhase::Won);
-    if !(restartable && requested) {
+    // No end screen yet means the death sequence is still playing (SPACE
+    // skips it instead).
+    if !(run_finished(run.game_state.phase) && !end_screens.is_empty() && requested) {
         return;
     }
 
-    // Despawn snake, food, and any in-flight eating effects (flashes,
-    // particles, score popups) so the new game starts visually clean.
-    for entity in segments.iter().chain(food.iter()).chain(effects.iter()) {
-        commands.entity(entity).despawn();
-    }
-    // Despawn whichever end-screen overlay is currently visible.
-    for entity in game_over_ui.iter().chain(win_ui.iter()) {
-        commands.entity(entity).despawn();
+    clear_finished_run(&mut commands, &segments, &food, &effects, &end_screens);
+    input_buffer.clear();
+    begin_new_game(&mut commands, &mut run);
+}
+
+/// System to leave an end screen for the start menu, on ESC or the MENU
+/// button — the way back to the mode selector.
+///
+/// A Zen run can't die, so ESC also leaves it straight from `Playing`.
+#[allow(clippy::too_many_arguments)]
+fn return_to_menu(
+    mut commands: Commands,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut menu_requests: MessageReader<ReturnToMenu>,
+    mut game_state: ResMut<GameState>,
+    game_mode: Res<GameMode>,
+    mut input_buffer: ResMut<InputBuffer>,
+    mut menu_screen: ResMut<MenuScreen>,
+    segments: SnakeEntityQuery,
+    food: Query<Entity, With<Food>>,
+    effects: EffectEntityQuery,
+    end_screens: EndScreenQuery,
+) {
+    // Drain unconditionally so a request from another phase can't linger.
+    let button_clicked = menu_requests.read().count() > 0;
+    let requested = button_clicked || keyboard_input.just_pressed(KeyCode::Escape);
+    let leaving_zen = *game_mode == GameMode::Zen && game_state.phase == GamePhase::Playing;
+    let on_end_screen = run_finished(game_state.phase) && !end_screens.is_empty();
+    if !((on_end_screen || leaving_zen) && requested) {
+        return;
     }
+
+    clear_finished_run(&mut commands, &segments, &food, &effects, &end_screens);
     input_buffer.clear();
-    begin_new_game(&mut commands, &mut game_state, &mut camera_shake);
+    game_state.snake_segments.clear();
+    game_state.phase = GamePhase::Menu;
+    // Force `show_menu_screen` to rebuild the main screen this frame.
+    *menu_screen = MenuScreen::Main;
+    menu_screen.set_changed();
 }
 
-/// System to update the score display.
+/// Advances the [`RunClock`] while a run is being played.
+fn advance_run_clock(time: Res<Time>, game_state: Res<GameState>, mut run_clock: ResMut<RunClock>) {
+    if game_state.phase == GamePhase::Playing {
+        run_clock.elapsed += time.delta();
+    }
+}
+
+/// Ends a Time Attack run with [`GamePhase::TimeUp`] once its limit elapses.
+fn time_attack_countdown(
+    game_mode: Res<GameMode>,
+    run_clock: Res<RunClock>,
+    mut game_state: ResMut<GameState>,
+) {
+    let Some(limit) = game_mode.time_limit() else {
+        return;
+    };
+    if game_state.phase == GamePhase::Playing && run_clock.elapsed >= limit {
+        game_state.phase = GamePhase::TimeUp;
+        info!("Time up! Final score: {}", game_state.score);
+    }
+}
+
+/// What the HUD bar shows; see [`update_hud_text`].
+#[derive(Clone, Copy, PartialEq)]
+struct HudValues {
+    score: usize,
+    /// The mode's stored record, or this run's score once it is higher.
+    best: usize,
+    /// Whether this run beats the stored record.
+    beaten: bool,
+    length: usize,
+    seconds: u64,
+    speed: Option<Speed>,
+    /// Share of the board's cells the snake covers.
+    fill: f32,
+}
+
+/// System to update the HUD bar: score, live best score, length, time
+/// played, speed, and the board-fill bar.
 ///
-/// Caches the last-rendered score in a `Local` and skips both the format and
-/// the component write when the score hasn't changed. This avoids the spurious
-/// re-render that `is_changed()` alone would trigger on every `GameState`
-/// mutation (phase changes, segment-vec updates, etc.) regardless of whether
-/// the score actually changed.
-fn update_score_text(
+/// Caches the last-rendered values in a `Local` and skips both the format and
+/// the component writes when none of them, the language nor the theme has
+/// changed. This avoids the spurious re-render that `is_changed()` alone
+/// would trigger on every `GameState` mutation (phase changes, segment-vec
+/// updates, etc.) regardless of whether anything shown actually changed.
+#[allow(clippy::too_many_arguments)]
+fn update_hud_text(
     game_state: Res<GameState>,
-    mut last_score: Local<Option<usize>>,
-    mut query: Query<&mut Text, With<ScoreText>>,
+    game_mode: Res<GameMode>,
+    run_clock: Res<RunClock>,
+    arena: Res<Arena>,
+    records: Records,
+    theme: Res<Theme>,
+    locale: Res<Locale>,
+    mut last_shown: Local<Option<HudValues>>,
+    mut texts: Query<(&HudText, &mut Text, &mut TextColor, &mut ThemeColor)>,
+    mut bars: Query<(&FillBar, &mut Node, &mut BackgroundColor)>,
+) {
+    let record = records.best(*game_mode);
+    let length = game_state.snake_segments.len();
+    let shown = HudValues {
+        score: game_state.score,
+        best: record.max(game_state.score),
+        beaten: game_state.score > record,
+        length,
+        seconds: run_clock.elapsed.as_secs(),
+        speed: Speed::from_move_interval(arena.move_interval),
+        fill: length as f32 / (arena.width * arena.height) as f32,
+    };
+    if *last_shown == Some(shown) && !locale.is_changed() && !theme.is_changed() {
+        return;
+    }
+    for (field, mut text, mut color, mut slot) in &mut texts {
+        let value = match field {
+            HudText::Score => locale.format("hud.score", &[("score", &shown.score)]),
+            HudText::Best => locale.format("hud.best", &[("best", &shown.best)]),
+            HudText::Length => locale.format("hud.length", &[("length", &shown.length)]),
+            HudText::Clock => clock(shown.seconds),
+            HudText::Speed => shown
+                .speed
+                .map_or_else(String::new, |speed| locale.text(speed.label_key())),
+        };
+        if text.0 != value {
+            *text = Text::from(value);
+        }
+        // The best score lights up in the record color once it is beaten.
+        if *field == HudText::Best {
+            *slot = if shown.beaten {
+                ThemeColor::Record
+            } else {
+                ThemeColor::Text
+            };
+            color.0 = theme.color(*slot);
+        }
+    }
+    for (part, mut node, mut background) in &mut bars {
+        background.0 = match part {
+            FillBar::Track => theme.dim.with_alpha(0.35),
+            FillBar::Fill => {
+                node.width = percent(shown.fill * 100.0);
+                theme.highlight
+            }
+        };
+    }
+    *last_shown = Some(shown);
+}
+
+/// `secs` as `m:ss`.
+fn clock(secs: u64) -> String {
+    format!("{}:{:02}", secs / 60, secs % 60)
+}
+
+/// System to update the Time Attack countdown, as `m:ss` remaining.
+///
+/// Same change guard as `update_hud_text`: the text is only rebuilt when
+/// the displayed whole second changes.
+fn update_timer_text(
+    game_mode: Res<GameMode>,
+    run_clock: Res<RunClock>,
+    mut last_shown: Local<Option<u64>>,
+    mut query: Query<&mut Text, With<TimerText>>,
 ) {
-    if *last_score == Some(game_state.score) {
+    let Some(limit) = game_mode.time_limit() else {
+        return;
+    };
+    // Round up so the display reads 0:01 until the very last moment.
+    let remaining = limit.saturating_sub(run_clock.elapsed);
+    let secs = remaining.as_millis().div_ceil(1000) as u64;
+    if *last_shown == Some(secs) {
         return;
     }
     let Ok(mut text) = query.single_mut() else {
         return;
     };
-    *text = Text::from(format!("Score: {}", game_state.score));
-    *last_score = Some(game_state.score);
+    *text = Text::from(clock(secs));
+    *last_shown = Some(secs);
 }
 
-/// Shows the score HUD only during `GamePhase::Playing`, hides it on the
-/// menu, game-over, and win screens. Tracks the previous phase in a `Local`
-/// so we only mutate `Visibility` on transitions.
+/// Shows the HUD only during `GamePhase::Playing`, hides it on the menu and
+/// end screens, lays out the countdown only in Time Attack, and the best
+/// score only in modes that keep records. Tracks the previous phase in a
+/// `Local` so we only mutate on transitions.
 fn update_score_visibility(
     game_state: Res<GameState>,
+    game_mode: Res<GameMode>,
     mut last_phase: Local<Option<GamePhase>>,
-    mut score: Query<&mut Visibility, With<ScoreText>>,
+    mut hud: Query<&mut Visibility, With<HudUI>>,
+    mut timer: Query<&mut Node, (With<TimerText>, Without<HudText>)>,
+    mut texts: Query<(&HudText, &mut Node)>,
 ) {
     if *last_phase == Some(game_state.phase) {
         return;
     }
-    let Ok(mut visibility) = score.single_mut() else {
+    let Ok(mut visibility) = hud.single_mut() else {
         return;
     };
     *visibility = if game_state.phase == GamePhase::Playing {
@@ -523,5 +1712,53 @@ fn update_score_visibility(
     } else {
         Visibility::Hidden
     };
+    if let Ok(mut node) = timer.single_mut() {
+        node.display = if game_mode.time_limit().is_some() {
+            Display::Flex
+        } else {
+            Display::None
+        };
+    }
+    for (field, mut node) in &mut texts {
+        if *field == HudText::Best {
+            node.display = if *game_mode == GameMode::Zen {
+                Display::None
+            } else {
+                Display::Flex
+            };
+        }
+    }
     *last_phase = Some(game_state.phase);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sparkline_steps_with_the_score() {
+        let seconds = Duration::from_secs;
+        let history = [(seconds(0), 0), (seconds(6), 1), (seconds(12), 2)];
+        // Column `c` ends `c` seconds in.
+        let columns = sparkline(&history, seconds(SPARKLINE_COLUMNS.into()));
+
+        assert_eq!(columns.len(), SPARKLINE_COLUMNS as usize);
+        assert_eq!(columns[4], 0.0);
+        assert_eq!(columns[5], 0.5);
+        assert_eq!(columns[11], 1.0);
+        assert_eq!(columns[SPARKLINE_COLUMNS as usize - 1], 1.0);
+    }
+
+    #[test]
+    fn an_empty_history_draws_flat() {
+        let columns = sparkline(&[], Duration::from_secs(30));
+        assert_eq!(columns, vec![0.0; SPARKLINE_COLUMNS as usize]);
+    }
+
+    #[test]
+    fn a_zero_length_run_draws_its_score_in_every_column() {
+        let history = [(Duration::ZERO, 0), (Duration::ZERO, 2)];
+        let columns = sparkline(&history, Duration::ZERO);
+        assert_eq!(columns, vec![1.0; SPARKLINE_COLUMNS as usize]);
+    }
+}
@@ -0,0 +1,732 @@
+//! The settings screen: one page per [`SettingsPage`] tab, each a list of
+//! choice rows, sliders, or key bindings.
+//!
+//! Every button writes an [`AdjustSetting`] message, and so does the
+//! keyboard / gamepad navigation, so `apply_setting_adjustments` is the one
+//! place settings change. Each change is persisted straight away.
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy::text::FontWeight;
+
+use super::{action_button, button_row, choice_button, label, overlay};
+use crate::game::{
+    ARENA_SIZES, AccessibilitySettings, AdjustSetting, AudioSettings, BodyStyle, ColorVision,
+    Direction, EffectsSettings, GamePhase, GameState, GameplaySettings, KeyBindings,
+    LanguageSettings, MenuNavigate, MenuScreen, MenuUI, SaveRequested, Setting, SettingsPage,
+    Speed, ThemeSettings,
+};
+use crate::locale::{Locale, LocaleLibrary};
+use crate::theme::{Theme, ThemeLibrary};
+
+/// How far one slider step moves, as a share of the full range.
+const SLIDER_STEP: f32 = 0.1;
+/// Number of cells in a slider's text bar.
+const SLIDER_CELLS: usize = 10;
+/// Keys that can't be bound to a direction: the arrows always move, and the
+/// rest already mean something on the menu or in a run. Escape cancels a
+/// rebind.
+const RESERVED_KEYS: [KeyCode; 14] = [
+    KeyCode::Escape,
+    KeyCode::Enter,
+    KeyCode::Space,
+    KeyCode::Tab,
+    KeyCode::KeyM,
+    KeyCode::F2,
+    KeyCode::F3,
+    KeyCode::F4,
+    KeyCode::F11,
+    KeyCode::F12,
+    KeyCode::ArrowUp,
+    KeyCode::ArrowDown,
+    KeyCode::ArrowLeft,
+    KeyCode::ArrowRight,
+];
+
+/// The rows of each settings page, top to bottom.
+fn page_settings(page: SettingsPage) -> &'static [Setting] {
+    match page {
+        SettingsPage::Gameplay => &[Setting::Speed, Setting::ArenaSize, Setting::Walls],
+        SettingsPage::Display => &[
+            Setting::Language,
+            Setting::Theme,
+            Setting::BodyStyle,
+            Setting::Bloom,
+            Setting::Shake,
+            Setting::Particles,
+        ],
+        SettingsPage::Accessibility => &[
+            Setting::ColorVision,
+            Setting::HighContrast,
+            Setting::ReduceMotion,
+        ],
+        SettingsPage::Audio => &[Setting::SoundVolume, Setting::MusicVolume, Setting::Muted],
+        SettingsPage::Controls => &[
+            Setting::Key(Direction::Up),
+            Setting::Key(Direction::Down),
+            Setting::Key(Direction::Left),
+            Setting::Key(Direction::Right),
+        ],
+    }
+}
+
+/// Catalogue key of the row title shown left of a setting's controls.
+fn setting_title(setting: Setting) -> &'static str {
+    match setting {
+        Setting::Speed => "setting.speed",
+        Setting::ArenaSize => "setting.arena_size",
+        Setting::Walls => "setting.walls",
+        Setting::Language => "setting.language",
+        Setting::Theme => "setting.theme",
+        Setting::BodyStyle => "setting.body_style",
+        Setting::Bloom => "setting.bloom",
+        Setting::Shake => "setting.shake",
+        Setting::Particles => "setting.particles",
+        Setting::ColorVision => "setting.color_vision",
+        Setting::HighContrast => "setting.high_contrast",
+        Setting::ReduceMotion => "setting.reduce_motion",
+        Setting::SoundVolume => "setting.sound",
+        Setting::MusicVolume => "setting.music",
+        Setting::Muted => "setting.muted",
+        Setting::Key(Direction::Up) => "setting.move_up",
+        Setting::Key(Direction::Down) => "setting.move_down",
+        Setting::Key(Direction::Left) => "setting.move_left",
+        Setting::Key(Direction::Right) => "setting.move_right",
+    }
+}
+
+/// Catalogue key of the one-line explanation under a page's rows.
+fn page_hint(page: SettingsPage) -> &'static str {
+    match page {
+        SettingsPage::Gameplay => "page_hint.gameplay",
+        SettingsPage::Display => "page_hint.display",
+        SettingsPage::Accessibility => "page_hint.accessibility",
+        SettingsPage::Audio => "page_hint.audio",
+        SettingsPage::Controls => "page_hint.controls",
+    }
+}
+
+/// Display name of a key: `KeyW` reads `W`, `Digit1` reads `1`.
+fn key_name(key: KeyCode) -> String {
+    let name = format!("{key:?}");
+    name.strip_prefix("Key")
+        .or_else(|| name.strip_prefix("Digit"))
+        .unwrap_or(&name)
+        .to_uppercase()
+}
+
+/// What a settings row shows and how it is adjusted.
+enum RowKind {
+    /// Pick one of `options`; steps wrap around.
+    Choice {
+        options: Vec<String>,
+        selected: usize,
+    },
+    /// A value in `0.0..=1.0`, stepped by [`SLIDER_STEP`].
+    Slider(f32),
+    /// The key bound to a direction; confirming listens for a new one.
+    Key(KeyCode),
+}
+
+/// Borrowed view of every value the settings screen edits.
+struct Values<'a> {
+    gameplay: &'a GameplaySettings,
+    effects: &'a EffectsSettings,
+    accessibility: &'a AccessibilitySettings,
+    audio: &'a AudioSettings,
+    keys: &'a KeyBindings,
+    theme: &'a ThemeSettings,
+    library: &'a ThemeLibrary,
+    language: &'a LanguageSettings,
+    locales: &'a LocaleLibrary,
+    /// The language option texts are shown in.
+    locale: &'a Locale,
+}
+
+impl Values<'_> {
+    /// The current state of `setting`'s row.
+    fn row(&self, setting: Setting) -> RowKind {
+        let text = |key: &str| self.locale.text(key);
+        let on_off = |on: bool| RowKind::Choice {
+            options: vec![text("settings.off"), text("settings.on")],
+            selected: on as usize,
+        };
+        match setting {
+            Setting::Speed => RowKind::Choice {
+                options: Speed::ALL.iter().map(|s| text(s.label_key())).collect(),
+                selected: Speed::ALL
+                    .iter()
+                    .position(|&s| s == self.gameplay.speed)
+                    .unwrap_or(0),
+            },
+            Setting::ArenaSize => RowKind::Choice {
+                options: ARENA_SIZES.iter().map(|n| format!("{n}x{n}")).collect(),
+                // A hand-edited size that isn't offered shows no selection.
+                selected: ARENA_SIZES
+                    .iter()
+                    .position(|&n| n == self.gameplay.arena_size)
+                    .unwrap_or(usize::MAX),
+            },
+            Setting::Walls => RowKind::Choice {
+                options: vec![text("settings.wrap"), text("settings.walls")],
+                selected: self.gameplay.walls as usize,
+            },
+            // Each language is listed under its own name.
+            Setting::Language => RowKind::Choice {
+                options: self
+                    .locales
+                    .locales
+                    .iter()
+                    .map(|l| l.name.to_uppercase())
+                    .collect(),
+                selected: self
+                    .locales
+                    .locales
+                    .iter()
+                    .position(|l| l.code == self.language.language)
+                    .unwrap_or(0),
+            },
+            Setting::Theme => RowKind::Choice {
+                options: self
+                    .library
+                    .themes
+                    .iter()
+                    .map(|t| t.name.to_uppercase())
+                    .collect(),
+                selected: self
+                    .library
+                    .themes
+                    .iter()
+                    .position(|t| t.name == self.theme.theme)
+                    .unwrap_or(0),
+            },
+            Setting::BodyStyle => RowKind::Choice {
+                options: BodyStyle::ALL.iter().map(|b| text(b.label_key())).collect(),
+                selected: BodyStyle::ALL
+                    .iter()
+                    .position(|&b| b == self.theme.body_style)
+                    .unwrap_or(0),
+            },
+            Setting::Bloom => RowKind::Slider(self.effects.bloom),
+            Setting::Shake => RowKind::Slider(self.effects.shake),
+            Setting::Particles => RowKind::Slider(self.effects.particles),
+            Setting::ColorVision => RowKind::Choice {
+                options: ColorVision::ALL
+                    .iter()
+                    .map(|v| text(v.label_key()))
+                    .collect(),
+                selected: ColorVision::ALL
+                    .iter()
+                    .position(|&v| v == self.accessibility.color_vision)
+                    .unwrap_or(0),
+            },
+            Setting::HighContrast => on_off(self.accessibility.high_contrast),
+            Setting::ReduceMotion => on_off(self.accessibility.reduce_motion),
+            Setting::SoundVolume => RowKind::Slider(self.audio.volume),
+            Setting::MusicVolume => RowKind::Slider(self.audio.music_volume),
+            Setting::Muted => on_off(self.audio.muted),
+            Setting::Key(direction) => RowKind::Key(self.keys.key(direction)),
+        }
+    }
+}
+
+/// Which row of the settings screen keyboard / gamepad input acts on: `0`
+/// is the page tabs, then one per setting, then BACK.
+#[derive(Resource, Default, PartialEq)]
+pub(super) struct SettingsCursor {
+    row: usize,
+}
+
+/// The direction whose key binding is waiting for a key press, if any.
+#[derive(Resource, Default)]
+pub(super) struct Rebinding(Option<Direction>);
+
+/// Read access to everything the settings screen shows.
+#[derive(SystemParam)]
+pub(super) struct SettingsView<'w> {
+    gameplay: Res<'w, GameplaySettings>,
+    effects: Res<'w, EffectsSettings>,
+    accessibility: Res<'w, AccessibilitySettings>,
+    audio: Res<'w, AudioSettings>,
+    keys: Res<'w, KeyBindings>,
+    theme: Res<'w, ThemeSettings>,
+    library: Res<'w, ThemeLibrary>,
+    language: Res<'w, LanguageSettings>,
+    locales: Res<'w, LocaleLibrary>,
+    locale: Res<'w, Locale>,
+    cursor: Res<'w, SettingsCursor>,
+    rebinding: Res<'w, Rebinding>,
+}
+
+impl SettingsView<'_> {
+    /// Whether anything on the settings screen needs redrawing.
+    pub(super) fn is_changed(&self) -> bool {
+        self.gameplay.is_changed()
+            || self.effects.is_changed()
+            || self.accessibility.is_changed()
+            || self.audio.is_changed()
+            || self.keys.is_changed()
+            || self.language.is_changed()
+            || self.cursor.is_changed()
+            || self.rebinding.is_changed()
+    }
+
+    /// The start menu's movement line, naming the bound keys.
+    pub(super) fn move_keys_hint(&self) -> String {
+        let names = [
+            Direction::Up,
+            Direction::Left,
+            Direction::Down,
+            Direction::Right,
+        ]
+        .map(|direction| key_name(self.keys.key(direction)));
+        // "WASD" reads better than "W/A/S/D"; longer names need separators.
+        let keys = if names.iter().all(|name| name.chars().count() == 1) {
+            names.concat()
+        } else {
+            names.join("/")
+        };
+        self.locale.format("menu.move_keys", &[("keys", &keys)])
+    }
+
+    fn values(&self) -> Values<'_> {
+        Values {
+            gameplay: &self.gameplay,
+            effects: &self.effects,
+            accessibility: &self.accessibility,
+            audio: &self.audio,
+            keys: &self.keys,
+            theme: &self.theme,
+            library: &self.library,
+            language: &self.language,
+            locales: &self.locales,
+            locale: &self.locale,
+        }
+    }
+}
+
+/// Write access to every value the settings screen edits.
+#[derive(SystemParam)]
+struct SettingsMut<'w> {
+    gameplay: ResMut<'w, GameplaySettings>,
+    effects: ResMut<'w, EffectsSettings>,
+    accessibility: ResMut<'w, AccessibilitySettings>,
+    audio: ResMut<'w, AudioSettings>,
+    keys: ResMut<'w, KeyBindings>,
+    theme: ResMut<'w, ThemeSettings>,
+    library: Res<'w, ThemeLibrary>,
+    language: ResMut<'w, LanguageSettings>,
+    locales: Res<'w, LocaleLibrary>,
+    locale: Res<'w, Locale>,
+}
+
+impl SettingsMut<'_> {
+    fn values(&self) -> Values<'_> {
+        Values {
+            gameplay: &self.gameplay,
+            effects: &self.effects,
+            accessibility: &self.accessibility,
+            audio: &self.audio,
+            keys: &self.keys,
+            theme: &self.theme,
+            library: &self.library,
+            language: &self.language,
+            locales: &self.locales,
+            locale: &self.locale,
+        }
+    }
+
+    /// Selects option `index` of a choice row.
+    fn set_choice(&mut self, setting: Setting, index: usize) {
+        match setting {
+            Setting::Speed => self.gameplay.speed = Speed::ALL[index],
+            Setting::ArenaSize => self.gameplay.arena_size = ARENA_SIZES[index],
+            Setting::Walls => self.gameplay.walls = index == 1,
+            Setting::Language => {
+                self.language.language = self.locales.locales[index].code.clone();
+            }
+            Setting::Theme => self.theme.theme = self.library.themes[index].name.clone(),
+            Setting::BodyStyle => self.theme.body_style = BodyStyle::ALL[index],
+            Setting::ColorVision => self.accessibility.color_vision = ColorVision::ALL[index],
+            Setting::HighContrast => self.accessibility.high_contrast = index == 1,
+            Setting::ReduceMotion => self.accessibility.reduce_motion = index == 1,
+            Setting::Muted => self.audio.muted = index == 1,
+            _ => {}
+        }
+    }
+
+    /// Sets a slider row to `value`.
+    fn set_slider(&mut self, setting: Setting, value: f32) {
+        match setting {
+            Setting::Bloom => self.effects.bloom = value,
+            Setting::Shake => self.effects.shake = value,
+            Setting::Particles => self.effects.particles = value,
+            Setting::SoundVolume => self.audio.volume = value,
+            Setting::MusicVolume => self.audio.music_volume = value,
+            _ => {}
+        }
+    }
+
+    /// Applies one choose / step request. Returns whether anything changed,
+    /// so untouched settings keep their change ticks.
+    fn adjust(&mut self, setting: Setting, choose: Option<usize>, step: i32) -> bool {
+        match self.values().row(setting) {
+            RowKind::Choice { options, selected } => {
+                let index = match choose {
+                    Some(index) => index,
+                    None => (selected.min(options.len()) as i32 + step)
+                        .rem_euclid(options.len() as i32) as usize,
+                };
+                if index == selected || index >= options.len() {
+                    return false;
+                }
+                self.set_choice(setting, index);
+            }
+            RowKind::Slider(value) => {
+                // Snap to whole steps so repeated presses land on 0% / 100%.
+                let target = ((value / SLIDER_STEP).round() + step as f32) * SLIDER_STEP;
+                let target = target.clamp(0.0, 1.0);
+                if choose.is_some() || target == value {
+                    return false;
+                }
+                self.set_slider(setting, target);
+            }
+            RowKind::Key(_) => return false,
+        }
+        true
+    }
+}
+
+/// The settings screen for `page`: the page tabs, one row per setting, a
+/// hint line, and a BACK button to the main menu, in `view`'s language.
+///
+/// The row under the keyboard / gamepad cursor has its title marked and
+/// drawn in the highlight color.
+pub(super) fn settings_screen(
+    page: SettingsPage,
+    view: &SettingsView,
+    theme: &Theme,
+) -> impl Scene + use<> {
+    let values = view.values();
+    let locale: &Locale = &view.locale;
+    let settings = page_settings(page);
+    // A tab click can leave the cursor past a shorter page's last row.
+    let cursor = view.cursor.row.min(settings.len() + 1);
+
+    let tabs: Vec<_> = SettingsPage::ALL
+        .into_iter()
+        .map(|tab| {
+            choice_button(
+                locale.text(tab.label_key()),
+                MenuNavigate(MenuScreen::Settings(tab)),
+                tab == page,
+                theme,
+            )
+        })
+        .collect();
+    let rows: Vec<_> = settings
+        .iter()
+        .enumerate()
+        .map(|(index, &setting)| {
+            let controls = setting_controls(setting, values.row(setting), view, theme);
+            let title = locale.text(setting_title(setting));
+            settings_row(&title, cursor == index + 1, controls, theme)
+        })
+        .collect();
+    let back = locale.text("menu.back");
+    let back_text = if cursor == settings.len() + 1 {
+        format!("> {back} <")
+    } else {
+        back
+    };
+    (
+        bsn! { MenuUI },
+        overlay(
+            0.85,
+            bsn_list![
+                label(locale.text("settings.title"), 8.5, theme.title, 3.8),
+                settings_row(&locale.text("settings.page"), cursor == 0, tabs, theme),
+                label(String::new(), 1.0, theme.hint, 1.9),
+                { rows },
+                label(locale.text(page_hint(page)), 2.9, theme.hint, 3.8),
+                action_button(back_text, MenuNavigate(MenuScreen::Main), theme),
+                label(locale.text("settings.nav_hint"), 2.6, theme.dim, 0.0),
+            ],
+        ),
+    )
+}
+
+/// One row of the settings screen: a fixed-width title, then its controls.
+fn settings_row<L: SceneList>(
+    title: &str,
+    focused: bool,
+    controls: L,
+    theme: &Theme,
+) -> impl Scene + use<L> {
+    let (text, color) = if focused {
+        (format!("> {title}"), theme.highlight)
+    } else {
+        (title.to_string(), theme.text)
+    };
+    bsn! {
+        Node {
+            flex_direction: FlexDirection::Row,
+            align_items: AlignItems::Center,
+            column_gap: { Val::VMin(1.5) },
+        }
+        Children [
+            (
+                Text(text)
+                TextFont {
+                    font_size: { FontSize::VMin(3.5) },
+                    weight: FontWeight::BOLD,
+                }
+                TextColor(color)
+                Node {
+                    width: { Val::VMin(30.0) },
+                    margin: { UiRect::bottom(Val::VMin(1.9)) },
+                }
+            ),
+            button_row({ controls }),
+        ]
+    }
+}
+
+/// The controls of one setting row: buttons, with a slider's text bar
+/// between its `-` and `+` buttons.
+fn setting_controls(
+    setting: Setting,
+    row: RowKind,
+    view: &SettingsView,
+    theme: &Theme,
+) -> impl SceneList + use<> {
+    let button = |text: String, request: AdjustSetting, selected: bool| {
+        choice_button(text, request, selected, theme)
+    };
+    let (before, bar, after) = match row {
+        RowKind::Choice { options, selected } => {
+            let buttons: Vec<_> = options
+                .into_iter()
+                .enumerate()
+                .map(|(index, text)| {
+                    button(
+                        text,
+                        AdjustSetting::Choose(setting, index),
+                        index == selected,
+                    )
+                })
+                .collect();
+            (buttons, None, Vec::new())
+        }
+        RowKind::Slider(value) => {
+            let filled = ((value * SLIDER_CELLS as f32).round() as usize).min(SLIDER_CELLS);
+            let bar = format!(
+                "[{}{}] {:>3}%",
+                "#".repeat(filled),
+                "-".repeat(SLIDER_CELLS - filled),
+                (value * 100.0).round() as u32
+            );
+            (
+                vec![button("-".into(), AdjustSetting::Step(setting, -1), false)],
+                Some(bsn_list![label(bar, 3.2, theme.text, 1.9)]),
+                vec![button("+".into(), AdjustSetting::Step(setting, 1), false)],
+            )
+        }
+        RowKind::Key(key) => {
+            let buttons = match setting {
+                Setting::Key(direction) => {
+                    let waiting = view.rebinding.0 == Some(direction);
+                    let text = if waiting {
+                        view.locale.text("settings.press_key")
+                    } else {
+                        key_name(key)
+                    };
+                    vec![button(text, AdjustSetting::Rebind(direction), waiting)]
+                }
+                _ => Vec::new(),
+            };
+            (buttons, None, Vec::new())
+        }
+    };
+    bsn_list![{ before }, { bar }, { after }]
+}
+
+/// Applies [`AdjustSetting`] requests and persists whatever changed.
+pub(super) fn apply_setting_adjustments(
+    mut saves: MessageWriter<SaveRequested>,
+    mut requests: MessageReader<AdjustSetting>,
+    game_state: Res<GameState>,
+    mut settings: SettingsMut,
+    mut rebinding: ResMut<Rebinding>,
+) {
+    // Drain unconditionally so a request from another phase can't linger.
+    let requests: Vec<AdjustSetting> = requests.read().cloned().collect();
+    if game_state.phase != GamePhase::Menu {
+        return;
+    }
+    let mut changed = false;
+    for request in requests {
+        changed |= match request {
+            AdjustSetting::Choose(setting, index) => settings.adjust(setting, Some(index), 0),
+            AdjustSetting::Step(setting, step) => settings.adjust(setting, None, step),
+            AdjustSetting::Rebind(direction) => {
+                rebinding.0 = Some(direction);
+                false
+            }
+        };
+    }
+    if changed {
+        saves.write(SaveRequested);
+    }
+}
+
+/// Binds the next key pressed to the direction awaiting a rebind.
+///
+/// ESC cancels; other reserved keys are ignored. A key already bound to
+/// another direction swaps with it so no direction is left without a key.
+/// The consumed key press is cleared so the navigation systems after this
+/// one don't also act on it.
+pub(super) fn capture_rebind(
+    mut saves: MessageWriter<SaveRequested>,
+    mut keyboard_input: ResMut<ButtonInput<KeyCode>>,
+    game_state: Res<GameState>,
+    mut rebinding: ResMut<Rebinding>,
+    mut keys: ResMut<KeyBindings>,
+) {
+    let Some(direction) = rebinding.0 else {
+        return;
+    };
+    if game_state.phase != GamePhase::Menu {
+        rebinding.0 = None;
+        return;
+    }
+    let Some(&key) = keyboard_input.get_just_pressed().next() else {
+        return;
+    };
+    keyboard_input.clear_just_pressed(key);
+    if key != KeyCode::Escape && RESERVED_KEYS.contains(&key) {
+        return;
+    }
+    rebinding.0 = None;
+    if key == KeyCode::Escape || keys.key(direction) == key {
+        return;
+    }
+    let previous = keys.key(direction);
+    if let Some(other) = Direction::ALL.into_iter().find(|&d| keys.key(d) == key) {
+        *keys.key_mut(other) = previous;
+    }
+    *keys.key_mut(direction) = key;
+    saves.write(SaveRequested);
+}
+
+/// One step of keyboard / gamepad navigation on the settings screen.
+#[derive(Clone, Copy, PartialEq)]
+enum NavInput {
+    Up,
+    Down,
+    Left,
+    Right,
+    Confirm,
+    Back,
+}
+
+/// This frame's navigation input from the keyboard and every gamepad.
+fn nav_input(
+    keyboard_input: &ButtonInput<KeyCode>,
+    gamepads: &Query<&Gamepad>,
+) -> Option<NavInput> {
+    let keyboard = [
+        (KeyCode::ArrowUp, NavInput::Up),
+        (KeyCode::ArrowDown, NavInput::Down),
+        (KeyCode::ArrowLeft, NavInput::Left),
+        (KeyCode::ArrowRight, NavInput::Right),
+        (KeyCode::Enter, NavInput::Confirm),
+    ];
+    let gamepad = [
+        (GamepadButton::DPadUp, NavInput::Up),
+        (GamepadButton::DPadDown, NavInput::Down),
+        (GamepadButton::DPadLeft, NavInput::Left),
+        (GamepadButton::DPadRight, NavInput::Right),
+        (GamepadButton::South, NavInput::Confirm),
+        (GamepadButton::East, NavInput::Back),
+    ];
+    keyboard
+        .into_iter()
+        .find(|&(key, _)| keyboard_input.just_pressed(key))
+        .or_else(|| {
+            gamepads.iter().find_map(|pad| {
+                gamepad
+                    .into_iter()
+                    .find(|&(button, _)| pad.just_pressed(button))
+            })
+        })
+        .map(|(_, input)| input)
+}
+
+/// Moves the settings cursor and acts on the focused row. ESC is handled by
+/// `navigate_menu` like on every other sub-screen; the gamepad's B does the
+/// same here.
+///
+/// Left/Right switch pages on the tab row and step the setting on a setting
+/// row; confirm cycles a choice, starts a rebind, or presses BACK.
+pub(super) fn navigate_settings(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    game_state: Res<GameState>,
+    rebinding: Res<Rebinding>,
+    mut menu_screen: ResMut<MenuScreen>,
+    mut cursor: ResMut<SettingsCursor>,
+    mut adjust: MessageWriter<AdjustSetting>,
+) {
+    let MenuScreen::Settings(page) = *menu_screen else {
+        // Every visit starts on the tab row.
+        cursor.set_if_neq(SettingsCursor::default());
+        return;
+    };
+    if game_state.phase != GamePhase::Menu || rebinding.0.is_some() {
+        return;
+    }
+    let Some(input) = nav_input(&keyboard_input, &gamepads) else {
+        return;
+    };
+
+    let settings = page_settings(page);
+    let back_row = settings.len() + 1;
+    // A page switch can leave the cursor past the new page's last row.
+    let row = cursor.row.min(back_row);
+    let setting = row.checked_sub(1).and_then(|i| settings.get(i)).copied();
+    let step = match input {
+        NavInput::Left => -1,
+        NavInput::Right => 1,
+        _ => 0,
+    };
+    match input {
+        NavInput::Up => cursor.row = (row + back_row) % (back_row + 1),
+        NavInput::Down => cursor.row = (row + 1) % (back_row + 1),
+        NavInput::Left | NavInput::Right if row == 0 => {
+            let tabs = SettingsPage::ALL.len() as i32;
+            let current = SettingsPage::ALL
+                .iter()
+                .position(|&p| p == page)
+                .unwrap_or(0);
+            let next = (current as i32 + step).rem_euclid(tabs) as usize;
+            *menu_screen = MenuScreen::Settings(SettingsPage::ALL[next]);
+        }
+        NavInput::Left | NavInput::Right => {
+            if let Some(setting) = setting {
+                adjust.write(AdjustSetting::Step(setting, step));
+            }
+        }
+        NavInput::Confirm => match setting {
+            Some(Setting::Key(direction)) => {
+                adjust.write(AdjustSetting::Rebind(direction));
+            }
+            Some(setting) => {
+                adjust.write(AdjustSetting::Step(setting, 1));
+            }
+            None if row == back_row => *menu_screen = MenuScreen::Main,
+            None => {}
+        },
+        NavInput::Back => *menu_screen = MenuScreen::Main,
+    }
+}